@@ -0,0 +1,238 @@
+use proc_macro::TokenStream;
+use proc_macro2::Span;
+use quote::{format_ident, quote, quote_spanned};
+use syn::{
+    parse::{Parse, ParseStream},
+    parse_macro_input,
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Data, DeriveInput, Error, Expr, Fields, GenericParam, Ident, Item, ItemFn, LitChar, LitStr, MetaNameValue, Token,
+};
+
+/// Registers a function as the solution to one part of a day's puzzle.
+///
+/// ```ignore
+/// #[aoc::part(day = 5, part = 1)]
+/// fn part1(input: &str) -> u64 { ... }
+/// ```
+///
+/// The function must take a single argument that can be converted from `&str`
+/// and return something implementing `AocResult`.
+///
+/// Several functions may be registered for the same part. Functions named `partN_suffix`
+/// are treated as variant `suffix` of part N, or the variant can be given explicitly with
+/// `variant = "name"`. All variants of a part are run and checked against each other.
+#[proc_macro_attribute]
+pub fn part(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr with Punctuated::<MetaNameValue, Token![,]>::parse_terminated);
+    let func = parse_macro_input!(item as ItemFn);
+
+    match expand_part(args, func) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+/// Attaches an example to a part function. The input is dedented like `indoc!`.
+///
+/// ```ignore
+/// #[aoc::example(part1, "seeds: 79 14 55 13 ...", 35)]
+/// ```
+#[proc_macro_attribute]
+pub fn example(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let args = parse_macro_input!(attr as ExampleArgs);
+    let item = parse_macro_input!(item as Item);
+
+    expand_example(args, item).into()
+}
+
+/// Implements `FromChar` for a fieldless enum, with each variant's characters given by `#[char(...)]`.
+///
+/// ```ignore
+/// #[derive(FromChar)]
+/// enum Tile {
+///     #[char('.')]
+///     Empty,
+///     #[char('#', '@')]
+///     Wall,
+/// }
+/// ```
+#[proc_macro_derive(FromChar, attributes(char))]
+pub fn derive_from_char(item: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(item as DeriveInput);
+
+    match expand_from_char(input) {
+        Ok(tokens) => tokens.into(),
+        Err(err) => err.into_compile_error().into(),
+    }
+}
+
+fn lit_u32(meta: &MetaNameValue) -> syn::Result<u32> {
+    match &meta.value {
+        Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(int), .. }) => int.base10_parse(),
+        other => Err(Error::new(other.span(), "expected an integer literal")),
+    }
+}
+
+fn lit_str(meta: &MetaNameValue) -> syn::Result<String> {
+    match &meta.value {
+        Expr::Lit(syn::ExprLit { lit: syn::Lit::Str(s), .. }) => Ok(s.value()),
+        other => Err(Error::new(other.span(), "expected a string literal")),
+    }
+}
+
+fn expand_part(args: Punctuated<MetaNameValue, Token![,]>, func: ItemFn) -> syn::Result<proc_macro2::TokenStream> {
+    let mut day = None;
+    let mut part = None;
+    let mut variant = None;
+
+    for meta in &args {
+        if meta.path.is_ident("day") {
+            day = Some(lit_u32(meta)?);
+        }
+        else if meta.path.is_ident("part") {
+            part = Some(lit_u32(meta)?);
+        }
+        else if meta.path.is_ident("variant") {
+            variant = Some(lit_str(meta)?);
+        }
+        else {
+            return Err(Error::new(meta.path.span(), "unknown argument, expected `day`, `part` or `variant`"));
+        }
+    }
+
+    let Some(day) = day else {
+        return Err(Error::new(Span::call_site(), "missing `day = N`"));
+    };
+    let Some(part) = part else {
+        return Err(Error::new(Span::call_site(), "missing `part = N`"));
+    };
+
+    let sig = &func.sig;
+    if let Some(param) = sig.generics.params.iter().find(|p| !matches!(p, GenericParam::Lifetime(_))) {
+        return Err(Error::new(param.span(), "part functions can only be generic over lifetimes"));
+    }
+    if sig.inputs.len() != 1 {
+        return Err(Error::new(sig.inputs.span(), "part functions take exactly one input argument"));
+    }
+    if let Some(asyncness) = &sig.asyncness {
+        return Err(Error::new(asyncness.span(), "part functions cannot be async"));
+    }
+
+    let ident = &sig.ident;
+    let name = ident.to_string();
+    let variant = variant.or_else(|| {
+        name.strip_prefix(&format!("part{}_", part)).map(str::to_string)
+    });
+    let variant = match variant {
+        Some(variant) => quote! { ::core::option::Option::Some(#variant) },
+        None => quote! { ::core::option::Option::None },
+    };
+    let meta_ident = format_ident!("__aoc_part_{}", ident);
+    let call = quote_spanned! {sig.output.span()=>
+        ::aoc_2023::AocResult::from_aoc(&#ident(::core::convert::Into::into(input)))
+    };
+
+    Ok(quote! {
+        #func
+
+        #[doc(hidden)]
+        #[allow(non_upper_case_globals)]
+        const #meta_ident: ::aoc_2023::runner::Part = ::aoc_2023::runner::Part {
+            day: #day,
+            part: #part,
+            name: #name,
+            variant: #variant,
+            run: {
+                fn run(input: &str) -> ::core::option::Option<::std::string::String> {
+                    #call
+                }
+                run
+            },
+        };
+
+        ::aoc_2023::__private::inventory::submit! { #meta_ident }
+    })
+}
+
+struct ExampleArgs {
+    part: Ident,
+    input: LitStr,
+    expected: Expr,
+}
+
+impl Parse for ExampleArgs {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let part = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let example_input = input.parse()?;
+        input.parse::<Token![,]>()?;
+        let expected = input.parse()?;
+        input.parse::<Option<Token![,]>>()?;
+        Ok(ExampleArgs { part, input: example_input, expected })
+    }
+}
+
+fn expand_example(ExampleArgs { part, input, expected }: ExampleArgs, item: Item) -> proc_macro2::TokenStream {
+    // Spanned to the user's identifier so a typo'd part name is reported there
+    let meta_ident = Ident::new(&format!("__aoc_part_{}", part), part.span());
+    let expected_str = quote_spanned! {expected.span()=>
+        ::std::string::ToString::to_string(&(#expected))
+    };
+
+    quote! {
+        #item
+
+        ::aoc_2023::__private::inventory::submit! {
+            ::aoc_2023::runner::Example {
+                day: #meta_ident.day,
+                part: #meta_ident.part,
+                line: ::core::line!(),
+                input: ::aoc_2023::__private::indoc!(#input),
+                expected: {
+                    fn expected() -> ::std::string::String {
+                        #expected_str
+                    }
+                    expected
+                },
+            }
+        }
+    }
+}
+
+fn expand_from_char(input: DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let Data::Enum(data) = &input.data else {
+        return Err(Error::new(input.ident.span(), "FromChar can only be derived for enums"));
+    };
+
+    let mut arms = Vec::new();
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new(variant.fields.span(), "FromChar variants cannot have fields"));
+        }
+        let Some(attr) = variant.attrs.iter().find(|a| a.path().is_ident("char")) else {
+            return Err(Error::new(variant.ident.span(), "missing `#[char('x')]`"));
+        };
+        let chars = attr.parse_args_with(Punctuated::<LitChar, Token![,]>::parse_terminated)?;
+        if chars.is_empty() {
+            return Err(Error::new(attr.span(), "expected at least one character"));
+        }
+        let chars = chars.iter();
+        let ident = &variant.ident;
+        arms.push(quote! { #(#chars)|* => ::core::option::Option::Some(Self::#ident), });
+    }
+
+    let ident = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+
+    Ok(quote! {
+        impl #impl_generics ::aoc_2023::utils::grid::FromChar for #ident #ty_generics #where_clause {
+            fn from_char(c: char) -> ::core::option::Option<Self> {
+                match c {
+                    #(#arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
+    })
+}
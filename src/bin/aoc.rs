@@ -0,0 +1,44 @@
+use std::process::ExitCode;
+
+use aoc_2023::gen;
+
+const USAGE: &str = "usage: aoc gen <day> [--size N] [--seed S]";
+
+fn flag(args: &[String], name: &str) -> Result<Option<u64>, String> {
+    match args.iter().position(|a| a == name) {
+        None => Ok(None),
+        Some(i) => args.get(i + 1)
+            .and_then(|v| v.parse().ok())
+            .map(Some)
+            .ok_or_else(|| format!("{} expects a number", name)),
+    }
+}
+
+fn run(args: &[String]) -> Result<(), String> {
+    match args.first().map(String::as_str) {
+        Some("gen") => {
+            let day = args.get(1)
+                .and_then(|d| d.parse().ok())
+                .ok_or_else(|| "expected a day number".to_string())?;
+            let size = flag(args, "--size")?.unwrap_or(10) as usize;
+            let seed = flag(args, "--seed")?.unwrap_or(0);
+
+            let generator = gen::for_day(day).ok_or_else(|| format!("no input generator for day {}", day))?;
+            // Solutions expect no trailing newline, matching the stored inputs
+            print!("{}", generator.generate(size, seed));
+            Ok(())
+        }
+        _ => Err(USAGE.to_string()),
+    }
+}
+
+fn main() -> ExitCode {
+    let args = std::env::args().skip(1).collect::<Vec<_>>();
+    match run(&args) {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(message) => {
+            eprintln!("{}", message);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use aho_corasick::AhoCorasick;
+
+use crate::aoc;
+
+use super::Solution;
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    type Input = Vec<String>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Vec<String> {
+        input.split("\n").map(str::to_string).collect()
+    }
+
+    fn part1(lines: &Vec<String>) -> i32 {
+        lines.iter().map(|line| {
+            let digits = line.chars()
+                .filter(|c| c.is_ascii_digit())
+                .collect::<Vec<char>>();
+
+            [digits.first().unwrap(), digits.last().unwrap()]
+                .into_iter()
+                .collect::<String>()
+                .parse()
+                .unwrap_or(0)
+        }).sum::<i32>()
+    }
+
+    fn part2(lines: &Vec<String>) -> i32 {
+        let fsm_forwards = AhoCorasick::builder()
+            .build([
+                "_", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+                "_", "1", "2", "3", "4", "5", "6", "7", "8", "9",
+            ])
+            .unwrap();
+
+        let fsm_backwards = AhoCorasick::builder()
+            .build([
+                "_", "eno", "owt", "eerht", "ruof", "evif", "xis", "neves", "thgie", "enin",
+                "_", "1", "2", "3", "4", "5", "6", "7", "8", "9",
+            ])
+            .unwrap();
+
+        lines.iter().map(|line| {
+            let first = fsm_forwards.find(line).unwrap().pattern().as_i32() % 10;
+
+            let backwards_line = line.chars().rev().collect::<String>();
+            let last = fsm_backwards.find(&backwards_line).unwrap().pattern().as_i32() % 10;
+
+            first * 10 + last
+        }).sum::<i32>()
+    }
+}
+
+#[aoc::example(part1, "1abc2
+    pqr3stu8vwx
+    a1b2c3d4e5f
+    treb7uchet", 142)]
+#[aoc::part(day = 1, part = 1)]
+pub fn part1(input: &str) -> i32 {
+    Day1::part1(&Day1::parse(input))
+}
+
+#[aoc::example(part2, "two1nine
+    eightwothree
+    abcone2threexyz
+    xtwone3four
+    4nineeightseven2
+    zoneight234
+    7pqrstsixteen", 281)]
+#[aoc::example(part2, "eightwo", 82)]
+#[aoc::part(day = 1, part = 2)]
+pub fn part2(input: &str) -> i32 {
+    Day1::part2(&Day1::parse(input))
+}
@@ -0,0 +1,238 @@
+use std::{collections::HashSet, fmt::Display};
+
+use itertools::Itertools;
+
+use crate::{aoc, utils::{geom::{Coord, Dir4}, grid::{Connectivity, FrameRecorder, FromChar, Grid}, search}};
+
+use super::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FromChar)]
+pub enum Pipe {
+    #[char('S')]
+    Start,
+    #[char('|')]
+    Vertical,
+    #[char('-')]
+    Horizontal,
+    #[char('L')]
+    NorthEast,
+    #[char('J')]
+    NorthWest,
+    #[char('7')]
+    SouthWest,
+    #[char('F')]
+    SouthEast,
+    #[char('.')]
+    Ground,
+}
+
+impl Pipe {
+    /// The directions this pipe leads. The start could lead anywhere.
+    pub fn connections(self) -> &'static [Dir4] {
+        match self {
+            Pipe::Start => &[Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right],
+            Pipe::Vertical => &[Dir4::Up, Dir4::Down],
+            Pipe::Horizontal => &[Dir4::Left, Dir4::Right],
+            Pipe::NorthEast => &[Dir4::Up, Dir4::Right],
+            Pipe::NorthWest => &[Dir4::Up, Dir4::Left],
+            Pipe::SouthWest => &[Dir4::Down, Dir4::Left],
+            Pipe::SouthEast => &[Dir4::Down, Dir4::Right],
+            Pipe::Ground => &[],
+        }
+    }
+}
+
+impl Display for Pipe {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let c = match self {
+            Pipe::Start => 'S',
+            Pipe::Vertical => '│',
+            Pipe::Horizontal => '─',
+            Pipe::NorthEast => '└',
+            Pipe::NorthWest => '┘',
+            Pipe::SouthWest => '┐',
+            Pipe::SouthEast => '┌',
+            Pipe::Ground => '.',
+        };
+        write!(f, "{}", c)
+    }
+}
+
+type GraphItem = (Coord, Option<Dir4>);
+
+fn get_neighbors(grid: &Grid<Pipe>, (pos, from_dir): GraphItem) -> Vec<GraphItem> {
+    let directions = grid[pos].connections();
+
+    let result = directions.iter().filter_map(|&dir| {
+        if from_dir == Some(dir.reverse()) {
+            return None;
+        }
+        let neighbor_pos = pos.step(dir)?;
+        let neighbor_valid = grid.get(neighbor_pos)?
+                .connections()
+                .iter()
+                .any(|neighbor_dir| *neighbor_dir == dir.reverse());
+        match neighbor_valid {
+            true => Some((neighbor_pos, Some(dir))),
+            false => None,
+        }
+    }).collect_vec();
+
+    result
+}
+
+/// Positions of every pipe in the loop through `S`, in order and ending with `S`.
+pub fn find_loop(grid: &Grid<Pipe>) -> Vec<Coord> {
+    let start_pos = Coord::from(grid.with_indices().find(|&(_, p)| *p == Pipe::Start).unwrap().0);
+
+    // Pipes can lead into `S` without being part of the loop, those paths dead end
+    get_neighbors(grid, (start_pos, None)).into_iter()
+        .find_map(|first| {
+            search::bfs(first, |&item| get_neighbors(grid, item), |&(pos, _)| pos == start_pos)
+        })
+        .unwrap()
+        .states
+        .into_iter()
+        .map(|(pos, _)| pos)
+        .collect_vec()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Grid<Pipe>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Grid<Pipe> {
+        Grid::parse(input).unwrap()
+    }
+
+    fn part1(grid: &Grid<Pipe>) -> usize {
+        find_loop(grid).len() / 2
+    }
+
+    fn part2(grid: &Grid<Pipe>) -> usize {
+        let path = HashSet::<Coord>::from_iter(find_loop(grid));
+
+        let cleaned_grid_items = grid.with_indices().map(|(pos, &pipe)| {
+            match path.contains(&pos.into()) {
+                true => pipe,
+                false => Pipe::Ground,
+            }
+        }).collect_vec();
+
+        // Padded so the fill can get all the way around a loop that touches the edge
+        let cleaned_grid = Grid::<Pipe>::new(grid.width, grid.height, cleaned_grid_items).pad(1, 1, 1, 1, Pipe::Ground);
+
+        let empty_spaces = cleaned_grid.iter().filter(|&&pipe| pipe == Pipe::Ground).count();
+
+        fn subdivide(pipe: Pipe) -> [[char; 3]; 3] {
+            match pipe {
+                Pipe::Start => [['x', 'x', 'x'],
+                                ['x', 'x', 'x'],
+                                ['x', 'x', 'x']],
+
+                Pipe::Vertical => [['.', 'x', '.'],
+                                   ['.', 'x', '.'],
+                                   ['.', 'x', '.']],
+
+                Pipe::Horizontal => [['.', '.', '.'],
+                                     ['x', 'x', 'x'],
+                                     ['.', '.', '.']],
+
+                Pipe::NorthEast => [['.', 'x', '.'],
+                                    ['.', 'x', 'x'],
+                                    ['.', '.', '.']],
+
+                Pipe::NorthWest => [['.', 'x', '.'],
+                                    ['x', 'x', '.'],
+                                    ['.', '.', '.']],
+
+                Pipe::SouthWest => [['.', '.', '.'],
+                                    ['x', 'x', '.'],
+                                    ['.', 'x', '.']],
+
+                Pipe::SouthEast => [['.', '.', '.'],
+                                    ['.', 'x', 'x'],
+                                    ['.', 'x', '.']],
+
+                Pipe::Ground => [[' ', ' ', ' '],
+                                 [' ', ' ', ' '],
+                                 [' ', ' ', ' ']],
+            }
+        }
+
+        // We'll subdivide the grid into 3x3 sections so we can flood fill it and get between the pipes
+        let fillable_grid = cleaned_grid.subdivide_by(subdivide);
+
+        // The padding guarantees the corner is outside the loop
+        let outside = fillable_grid.flood_fill((0, 0), |&c| c == ' ' || c == '.', Connectivity::Four);
+        let filled_empty_spaces = outside.cells.iter().filter(|&&c| fillable_grid[c] == ' ').count();
+
+        let mut recorder = FrameRecorder::if_recording(|&c| match c {
+            'x' => [240, 240, 240],
+            'o' => [60, 110, 200],
+            _ => [20, 20, 20],
+        }).with_scale(2);
+        if recorder.is_enabled() {
+            let mut frame = fillable_grid.clone();
+            for cells in outside.cells.chunks(outside.area() / 100 + 1) {
+                cells.iter().for_each(|&c| frame[c] = 'o');
+                recorder.push(&frame);
+            }
+        }
+        recorder.finish("recordings/day10");
+        
+        empty_spaces - (filled_empty_spaces / 9)
+    }
+}
+
+#[aoc::example(part1, ".....
+    .S-7.
+    .|.|.
+    .L-J.
+    .....", 4)]
+#[aoc::example(part1, "..F7.
+    .FJ|.
+    SJ.L7
+    |F--J
+    LJ...", 8)]
+#[aoc::part(day = 10, part = 1)]
+pub fn part1(input: &str) -> usize {
+    Day10::part1(&Day10::parse(input))
+}
+
+#[aoc::example(part2, "...........
+    .S-------7.
+    .|F-----7|.
+    .||.....||.
+    .||.....||.
+    .|L-7.F-J|.
+    .|..|.|..|.
+    .L--J.L--J.
+    ...........", 4)]
+#[aoc::example(part2, ".F----7F7F7F7F-7....
+    .|F--7||||||||FJ....
+    .||.FJ||||||||L7....
+    FJL7L7LJLJ||LJ.L-7..
+    L--J.L7...LJS7F-7L7.
+    ....F-J..F7FJ|L7L7L7
+    ....L7.F7||L7|.L7L7|
+    .....|FJLJ|FJ|F7|.LJ
+    ....FJL-7.||.||||...
+    ....L---J.LJ.LJLJ...", 8)]
+#[aoc::example(part2, "FF7FSF7F7F7F7F7F---7
+    L|LJ||||||||||||F--J
+    FL-7LJLJ||||||LJL-77
+    F--JF--7||LJLJ7F7FJ-
+    L---JF-JLJ.||-FJLJJ7
+    |F|F-JF---7F7-L7L|7|
+    |FFJF7L7F-JF7|JL---7
+    7-L-JL7||F7|L7F-7F7|
+    L.L7LFJ|||||FJL7||LJ
+    L7JLJL-JLJLJL--JLJ.L", 10)]
+#[aoc::part(day = 10, part = 2)]
+pub fn part2(input: &str) -> usize {
+    Day10::part2(&Day10::parse(input))
+}
@@ -0,0 +1,90 @@
+use itertools::Itertools;
+
+use crate::{aoc, utils::grid::{Grid, SparseGrid}};
+
+use super::Solution;
+
+fn is_empty<'a>(mut cells: impl Iterator<Item = &'a char>) -> bool {
+    cells.all(|&c| c == '.')
+}
+
+/// The galaxies after every empty row and column has been replaced by `factor` of them,
+/// without building the whole expanded universe.
+fn expand_sparse(grid: &Grid<char>, factor: usize) -> SparseGrid<char> {
+    let empty_cols_before = grid.col_prefix_sums(|col| is_empty(col) as usize);
+    let empty_rows_before = grid.row_prefix_sums(|row| is_empty(row) as usize);
+    let mut galaxies = SparseGrid::new('.');
+
+    for ((x, y), &c) in grid.with_indices() {
+        if c == '#' {
+            let x = x + empty_cols_before[x] * (factor - 1);
+            let y = y + empty_rows_before[y] * (factor - 1);
+            galaxies.insert((x as isize, y as isize), c);
+        }
+    }
+
+    galaxies
+}
+
+fn sum_distances(galaxies: impl Iterator<Item = (usize, usize)>) -> usize {
+    galaxies
+        .collect_vec()
+        .into_iter()
+        .tuple_combinations()
+        .map(|((x1, y1), (x2, y2))| x1.abs_diff(x2) + y1.abs_diff(y2))
+        .sum()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Grid<char>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Grid<char> {
+        input.into()
+    }
+
+    fn part1(grid: &Grid<char>) -> usize {
+        let expanded = grid
+            .duplicate_rows_where(|row| is_empty(row), 2)
+            .duplicate_cols_where(|col| is_empty(col), 2);
+        sum_distances(expanded.with_indices().filter(|&(_, &c)| c == '#').map(|(pos, _)| pos))
+    }
+
+    fn part2(grid: &Grid<char>) -> usize {
+        let galaxies = expand_sparse(grid, 1_000_000);
+        sum_distances(galaxies.iter().map(|((x, y), _)| (x as usize, y as usize)))
+    }
+}
+
+#[aoc::example(part1, "...#......
+    .......#..
+    #.........
+    ..........
+    ......#...
+    .#........
+    .........#
+    ..........
+    .......#..
+    #...#.....", 374)]
+#[aoc::part(day = 11, part = 1)]
+pub fn part1(input: &str) -> usize {
+    Day11::part1(&Day11::parse(input))
+}
+
+// #[aoc::example(part2, "...#......
+//     .......#..
+//     #.........
+//     ..........
+//     ......#...
+//     .#........
+//     .........#
+//     ..........
+//     .......#..
+//     #...#.....", 1030)]
+#[aoc::part(day = 11, part = 2)]
+pub fn part2(input: &str) -> usize {
+    Day11::part2(&Day11::parse(input))
+}
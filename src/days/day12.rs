@@ -0,0 +1,228 @@
+use std::{str::FromStr, iter, collections::{HashMap, VecDeque}};
+
+use itertools::Itertools;
+
+use crate::aoc;
+
+use super::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State { Operational, Damaged, Unknown }
+
+#[derive(Debug, Clone)]
+pub struct Row {
+    pub state: Vec<State>,
+    pub spans: Vec<usize>,
+}
+
+impl Row {
+    /// Repeats the row five times, joining the states with unknowns.
+    pub fn unfold(self) -> Row {
+        let state = vec![self.state; 5].join(&State::Unknown);
+        let spans = self.spans.repeat(5);
+        Row { state, spans }
+    }
+
+    /// Enumerates every placement of the spans with stars and bars. Exponential, only viable for folded rows.
+    pub fn count_arrangements_by_combination(self) -> usize {
+        // Treat each specified span as a sequence of '#'s preceded by a '.'. e.g., 4 would be ".####".
+        // In order to make this work, we also need to prepend a single '.' to the start of the row.
+
+        // The total length of the row minus the number of slots in these spans (including the '.' character)
+        // is now the number of '.'s that we need to insert before/after/between the spans to obtain a
+        // row candidate.
+        
+        // Then filter out only the row candidates which work, and count how many there are.
+
+        let states = iter::once(&State::Operational).chain(self.state.iter()).collect_vec();
+        let extra_operationals = states.len() - self.spans.iter().map(|x| x + 1).sum::<usize>();
+        let bins = self.spans.len() + 1;
+        let stars_bars_bins = extra_operationals + bins - 1;
+
+        (0..stars_bars_bins)
+            .combinations(bins - 1)
+            .filter(|bar_positions| {
+                // This contains the number of '.'s in each position (before/after/between spans)
+                let combination = iter::once(-1)
+                    .chain(bar_positions.iter().map(|&n| n as i32))
+                    .chain(iter::once(stars_bars_bins as i32))
+                    .tuple_windows()
+                    .map(|(a, b)| (b - a - 1) as usize);
+
+                // This is the final candidate
+                let candidate = combination
+                    .enumerate()
+                    .flat_map(|(i, n)| {
+                        iter::repeat_n(&State::Operational, n + 1)
+                            .chain(iter::repeat_n(&State::Damaged, {
+                                if i < self.spans.len() { self.spans[i] } else { 0 } 
+                            }))
+                    });
+
+                // Make sure to prepend the '.' to the row, then compare against the candidate
+                iter::once(&State::Operational).chain(self.state.iter())
+                    .zip(candidate)
+                    .all(|p| matches!(p,
+                        (State::Operational, State::Operational)
+                        | (State::Damaged, State::Damaged)
+                        | (State::Unknown, _)
+                    ))
+            })
+            .count()
+    }
+
+    /// Counts arrangements by merging partial placements that have reached the same point in the row.
+    pub fn count_arrangements_by_progress(self) -> usize {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        struct Progress {
+            state_offset: usize,
+            span_idx: usize,
+        }
+
+        // Every span is treated as a sequence of n '#'s followed by a '.'.
+        // In order for this to be valid, we need to append an extra '.' to the end of the state.
+
+        let state = self.state.into_iter().chain(iter::once(State::Operational)).collect_vec();
+
+        let mut ct = 0;
+        let mut progress_counts = HashMap::<Progress, usize>::new();
+        let mut progress_queue = VecDeque::<Progress>::new();
+
+        macro_rules! enqueue {
+            ($progress:expr, $n:expr) => {
+                if let Some(ct_ref) = progress_counts.get_mut(&$progress) {
+                    *ct_ref += $n;
+                }
+                else {
+                    progress_counts.insert($progress, $n);
+                    progress_queue.push_back($progress);
+                }
+            };
+        }
+
+        enqueue!(Progress { state_offset: 0, span_idx: 0 }, 1);
+
+        while let Some(progress) = progress_queue.pop_front() {
+            let Progress { state_offset, span_idx } = progress;
+            let progress_count = progress_counts[&progress];
+
+            if span_idx == self.spans.len() {
+                if state[state_offset..].iter().all(|&s| s != State::Damaged) {
+                    ct += progress_count;
+                }
+                continue;
+            }
+
+            if state_offset >= state.len() {
+                continue;
+            }
+
+            if state[state_offset] != State::Damaged {
+                // Effectively operational
+                enqueue!(Progress { state_offset: state_offset + 1, span_idx }, progress_count);
+            }
+
+            if state[state_offset] != State::Operational {
+                // Effectively damaged
+                let damaged_slice_end = state_offset + self.spans[span_idx];
+                if damaged_slice_end >= state.len() {
+                    continue;
+                }
+
+                if state[damaged_slice_end] != State::Damaged {
+                    // The space after the span could be operational
+                    
+                    let damaged_slice = &state[state_offset + 1..damaged_slice_end];
+                    if damaged_slice.iter().all(|&s| s != State::Operational) {
+                        // The span could all be damaged
+                        enqueue!(Progress {
+                            state_offset: damaged_slice_end + 1,
+                            span_idx: span_idx + 1
+                        }, progress_count);
+                    }
+                }
+            }
+        }
+
+        ct
+    }
+}
+
+impl FromStr for Row {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (states_str, spans_str) = s.split(' ').next_tuple().ok_or(())?;
+        Ok(Row {
+            state: states_str.chars()
+                .map(|c| match c {
+                    '.' => State::Operational,
+                    '#' => State::Damaged,
+                    _ => State::Unknown,
+                })
+                .collect_vec(),
+            spans: spans_str.split(',')
+                .map(|s| s.parse::<usize>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|_| ())?,
+        })
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Row>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Vec<Row> {
+        input.lines().map(|l| l.parse::<Row>().unwrap()).collect_vec()
+    }
+
+    fn part1(rows: &Vec<Row>) -> usize {
+        rows.iter()
+            .cloned()
+            .map(Row::count_arrangements_by_combination)
+            .sum()
+    }
+
+    fn part2(rows: &Vec<Row>) -> usize {
+        rows.iter()
+            .cloned()
+            .map(Row::unfold)
+            .map(Row::count_arrangements_by_progress)
+            .sum()
+    }
+}
+
+#[aoc::example(part1, "???.### 1,1,3
+    .??..??...?##. 1,1,3
+    ?#?#?#?#?#?#?#? 1,3,1,6
+    ????.#...#... 4,1,1
+    ????.######..#####. 1,6,5
+    ?###???????? 3,2,1", 21)]
+#[aoc::part(day = 12, part = 1)]
+pub fn part1(input: &str) -> usize {
+    Day12::part1(&Day12::parse(input))
+}
+
+#[aoc::part(day = 12, part = 1)]
+pub fn part1_progress(input: &str) -> usize {
+    Day12::parse(input)
+        .into_iter()
+        .map(Row::count_arrangements_by_progress)
+        .sum()
+}
+
+#[aoc::example(part2, "???.### 1,1,3
+    .??..??...?##. 1,1,3
+    ?#?#?#?#?#?#?#? 1,3,1,6
+    ????.#...#... 4,1,1
+    ????.######..#####. 1,6,5
+    ?###???????? 3,2,1", 525152)]
+#[aoc::example(part2, "???.### 1,1,3", 1)]
+#[aoc::example(part2, ".??..??...?##. 1,1,3", 16384)]
+#[aoc::part(day = 12, part = 2)]
+pub fn part2(input: &str) -> usize {
+    Day12::part2(&Day12::parse(input))
+}
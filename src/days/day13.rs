@@ -0,0 +1,153 @@
+use itertools::Itertools;
+
+use crate::{aoc, utils::grid::{Axis, BitGrid, ColumnMajor, Grid, Layout, ReflectionAxis, RowMajor, Tiled}};
+
+use super::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Reflection {
+    Vertical(usize),
+    Horizontal(usize),
+}
+
+impl Reflection {
+    pub fn summarize(self) -> usize {
+        match self {
+            Reflection::Vertical(x) => x,
+            Reflection::Horizontal(y) => 100 * y,
+        }
+    }
+}
+
+/// The first of `axes` with exactly `defects` mismatches, which prefers horizontal lines.
+fn pick_reflection(axes: Vec<ReflectionAxis>, defects: usize) -> Option<Reflection> {
+    axes.into_iter()
+        .find(|axis| axis.defects() == defects)
+        .map(|axis| match axis.axis {
+            Axis::Horizontal => Reflection::Horizontal(axis.position),
+            Axis::Vertical => Reflection::Vertical(axis.position),
+        })
+}
+
+/// Finds the mirror line where exactly `defects` cells differ from their mirror image, preferring horizontal lines.
+pub fn find_reflection(grid: &BitGrid, defects: usize) -> Option<Reflection> {
+    pick_reflection(grid.reflection_axes(defects), defects)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<BitGrid>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Vec<BitGrid> {
+        input.split("\n\n")
+            .map(|pattern| BitGrid::from_grid(&Grid::<char>::from(pattern), |&c| c == '#'))
+            .collect_vec()
+    }
+
+    fn part1(grids: &Vec<BitGrid>) -> usize {
+        grids.iter()
+            .map(|grid| find_reflection(grid, 0).unwrap().summarize())
+            .sum::<usize>()
+    }
+
+    fn part2(grids: &Vec<BitGrid>) -> usize {
+        grids.iter()
+            .map(|grid| find_reflection(grid, 1).unwrap().summarize())
+            .sum::<usize>()
+    }
+}
+
+#[aoc::example(part1, "#.##..##.
+    ..#.##.#.
+    ##......#
+    ##......#
+    ..#.##.#.
+    ..##..##.
+    #.#.##.#.
+    
+    #...##..#
+    #....#..#
+    ..##..###
+    #####.##.
+    #####.##.
+    ..##..###
+    #....#..#", 405)]
+#[aoc::example(part1, "..##..##.....#...
+    .##.#..###..#..#.
+    ###...###.#...##.
+    #..#.###...#.###.
+    #..#.###...#.###.
+    ###...###.#..###.
+    .##.#..###..#..#.
+    ..##..##.....#...
+    #..#..#.#.#.#.##.
+    #.#..##.##..#.#.#
+    ##....####.####..
+    ##.###.#.#.#####.
+    ##.###.#.#.#####.", 1200)]
+#[aoc::part(day = 13, part = 1)]
+pub fn part1(input: &str) -> usize {
+    Day13::part1(&Day13::parse(input))
+}
+
+// The layout variants compare cells by index instead of as bits, to compare layouts with `day13 bench`
+
+fn summarize_with_layout<L : Layout>(input: &str, defects: usize) -> usize {
+    input.split("\n\n")
+        .map(Grid::<char, L>::from)
+        .map(|grid| pick_reflection(grid.reflection_axes(defects), defects).unwrap().summarize())
+        .sum()
+}
+
+#[aoc::part(day = 13, part = 1)]
+pub fn part1_row_major(input: &str) -> usize {
+    summarize_with_layout::<RowMajor>(input, 0)
+}
+
+#[aoc::part(day = 13, part = 1)]
+pub fn part1_column_major(input: &str) -> usize {
+    summarize_with_layout::<ColumnMajor>(input, 0)
+}
+
+#[aoc::part(day = 13, part = 1)]
+pub fn part1_tiled(input: &str) -> usize {
+    summarize_with_layout::<Tiled>(input, 0)
+}
+
+#[aoc::example(part2, "#.##..##.
+    ..#.##.#.
+    ##......#
+    ##......#
+    ..#.##.#.
+    ..##..##.
+    #.#.##.#.
+    
+    #...##..#
+    #....#..#
+    ..##..###
+    #####.##.
+    #####.##.
+    ..##..###
+    #....#..#", 400)]
+#[aoc::part(day = 13, part = 2)]
+pub fn part2(input: &str) -> usize {
+    Day13::part2(&Day13::parse(input))
+}
+
+#[aoc::part(day = 13, part = 2)]
+pub fn part2_row_major(input: &str) -> usize {
+    summarize_with_layout::<RowMajor>(input, 1)
+}
+
+#[aoc::part(day = 13, part = 2)]
+pub fn part2_column_major(input: &str) -> usize {
+    summarize_with_layout::<ColumnMajor>(input, 1)
+}
+
+#[aoc::part(day = 13, part = 2)]
+pub fn part2_tiled(input: &str) -> usize {
+    summarize_with_layout::<Tiled>(input, 1)
+}
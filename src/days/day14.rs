@@ -0,0 +1,199 @@
+use std::collections::{HashMap, VecDeque};
+
+use crate::{aoc, utils::grid::{ColumnMajor, FrameRecorder, FromChar, Grid, Layout, Rgb, Tiled, Transform}};
+
+use super::Solution;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FromChar)]
+pub enum Rock {
+    #[char('O')]
+    Round,
+    #[char('#')]
+    Cube,
+    #[char('.')]
+    Empty,
+}
+
+impl Rock {
+    pub fn color(&self) -> Rgb {
+        match self {
+            Rock::Round => [230, 190, 70],
+            Rock::Cube => [110, 110, 120],
+            Rock::Empty => [25, 25, 35],
+        }
+    }
+}
+
+/// Rolls every round rock toward the front of `line` until it hits a cube rock or another round rock.
+fn tilt<'a>(line: impl Iterator<Item = &'a mut Rock>) {
+    // Empty cells a rock could still roll into, nearest first
+    let mut empty = VecDeque::<&mut Rock>::new();
+    for cell in line {
+        match cell {
+            Rock::Cube => empty.clear(),
+            Rock::Empty => empty.push_back(cell),
+            Rock::Round => {
+                if let Some(target) = empty.pop_front() {
+                    *target = Rock::Round;
+                    *cell = Rock::Empty;
+                    empty.push_back(cell);
+                }
+            }
+        }
+    }
+}
+
+/// Tilts the platform north, west, south and then east.
+fn spin_cycle<L : Layout>(grid: &mut Grid<Rock, L>) {
+    for column in grid.columns_mut() {
+        tilt(column);
+    }
+    for row in grid.rows_mut() {
+        tilt(row);
+    }
+    for column in grid.columns_mut() {
+        tilt(column.rev());
+    }
+    for row in grid.rows_mut() {
+        tilt(row.rev());
+    }
+}
+
+/// The grid after a billion spin cycles, skipping ahead once the states start repeating.
+fn after_spin_cycles<L : Layout>(mut grid: Grid<Rock, L>, mut spin_cycle: impl FnMut(&mut Grid<Rock, L>)) -> Grid<Rock, L> {
+    let mut seen_states = Vec::<Grid<Rock, L>>::new();
+    let mut seen_states_map = HashMap::<u64, usize>::new();
+
+    for i in 0.. {
+        spin_cycle(&mut grid);
+        let fingerprint = grid.fingerprint();
+        if let Some(&seen_idx) = seen_states_map.get(&fingerprint).filter(|&&idx| seen_states[idx] == grid) {
+            // Once we hit a cycle, stop
+            let loop_len = i - seen_idx;
+            let loop_offset = (1_000_000_000 - seen_idx - 1) % loop_len;
+            return seen_states.swap_remove(seen_idx + loop_offset);
+        }
+        seen_states.push(grid.clone());
+        seen_states_map.insert(fingerprint, i);
+    }
+    unreachable!()
+}
+
+fn north_load<L : Layout>(grid: &Grid<Rock, L>) -> usize {
+    grid.with_indices()
+        .filter(|&(_, &rock)| rock == Rock::Round)
+        .map(|((_, y), _)| grid.height - y)
+        .sum()
+}
+
+/// Load on the north beams after tilting north, counted without moving any rocks.
+fn part1_with_layout<L : Layout>(grid: &Grid<Rock, L>) -> usize {
+    struct RockStack {
+        start_row: usize,
+        num_rocks: usize,
+    }
+
+    grid.view(Transform::Transpose).rows()
+        .flat_map(|column| {
+            let mut stacks = Vec::<RockStack>::new();
+            let mut rocks_in_stack = 0;
+            let column_len = column.len();
+            for (row, c) in column.rev().enumerate() {
+                match c {
+                    Rock::Round => rocks_in_stack += 1,
+                    Rock::Cube => {
+                        stacks.push(RockStack { start_row: row, num_rocks: rocks_in_stack });
+                        rocks_in_stack = 0;
+                    },
+                    Rock::Empty => {}
+                }
+            }
+            stacks.push(RockStack { start_row: column_len, num_rocks: rocks_in_stack });
+            stacks
+        })
+        .map(|RockStack { start_row, num_rocks }| (start_row - num_rocks + 1..=start_row).sum::<usize>())
+        .sum::<usize>()
+}
+
+fn part2_with_layout<L : Layout>(grid: Grid<Rock, L>) -> usize {
+    let mut recorder = FrameRecorder::if_recording(Rock::color).with_scale(4);
+    let grid = after_spin_cycles(grid, |grid| {
+        spin_cycle(grid);
+        recorder.push(grid);
+    });
+    recorder.finish("recordings/day14");
+
+    north_load(&grid)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Grid<Rock>;
+    type Output1 = usize;
+    type Output2 = usize;
+
+    fn parse(input: &str) -> Grid<Rock> {
+        Grid::parse(input).unwrap()
+    }
+
+    fn part1(grid: &Grid<Rock>) -> usize {
+        part1_with_layout(grid)
+    }
+
+    fn part2(grid: &Grid<Rock>) -> usize {
+        part2_with_layout(grid.clone())
+    }
+}
+
+#[aoc::example(part1, "O....#....
+    O.OO#....#
+    .....##...
+    OO.#O....O
+    .O.....O#.
+    O.#..O.#.#
+    ..O..#O..O
+    .......O..
+    #....###..
+    #OO..#....", 136)]
+#[aoc::part(day = 14, part = 1)]
+pub fn part1(input: &str) -> usize {
+    Day14::part1(&Day14::parse(input))
+}
+
+// The layout variants run the primary code on the other layouts, to compare them with `day14 bench`
+
+#[aoc::part(day = 14, part = 1)]
+pub fn part1_column_major(input: &str) -> usize {
+    part1_with_layout(&Day14::parse(input).into_layout::<ColumnMajor>())
+}
+
+#[aoc::part(day = 14, part = 1)]
+pub fn part1_tiled(input: &str) -> usize {
+    part1_with_layout(&Day14::parse(input).into_layout::<Tiled>())
+}
+
+#[aoc::example(part2, "O....#....
+    O.OO#....#
+    .....##...
+    OO.#O....O
+    .O.....O#.
+    O.#..O.#.#
+    ..O..#O..O
+    .......O..
+    #....###..
+    #OO..#....", 64)]
+#[aoc::part(day = 14, part = 2)]
+pub fn part2(input: &str) -> usize {
+    Day14::part2(&Day14::parse(input))
+}
+
+#[aoc::part(day = 14, part = 2)]
+pub fn part2_column_major(input: &str) -> usize {
+    part2_with_layout(Day14::parse(input).into_layout::<ColumnMajor>())
+}
+
+#[aoc::part(day = 14, part = 2)]
+pub fn part2_tiled(input: &str) -> usize {
+    part2_with_layout(Day14::parse(input).into_layout::<Tiled>())
+}
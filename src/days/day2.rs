@@ -0,0 +1,106 @@
+use regex::Regex;
+
+use crate::aoc;
+
+use super::Solution;
+
+/// The cubes revealed in a single draw, or the fewest needed for a whole game.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Cubes {
+    pub red: i32,
+    pub green: i32,
+    pub blue: i32,
+}
+
+pub struct Game {
+    pub id: i32,
+    pub draws: Vec<Cubes>,
+}
+
+impl Game {
+    pub fn fewest_cubes(&self) -> Cubes {
+        self.draws.iter().fold(Cubes::default(), |acc, draw| Cubes {
+            red: acc.red.max(draw.red),
+            green: acc.green.max(draw.green),
+            blue: acc.blue.max(draw.blue),
+        })
+    }
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    type Input = Vec<Game>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Vec<Game> {
+        let re = Regex::new(r"(\d+) (red|green|blue)").unwrap();
+
+        input.split("\n").map(|game| {
+            let (id_str, draws_str) = game.split_once(": ").unwrap();
+            let id = id_str.trim_start_matches("Game ").parse().unwrap();
+            let draws = draws_str.split("; ").map(|draw| {
+                let mut cubes = Cubes::default();
+                for caps in re.captures_iter(draw) {
+                    let amt = caps[1].parse::<i32>().unwrap();
+                    match caps[2].chars().next().unwrap() {
+                        'r' => cubes.red += amt,
+                        'g' => cubes.green += amt,
+                        'b' => cubes.blue += amt,
+                        _ => panic!("Unexpected color: {}", &caps[2]),
+                    }
+                }
+                cubes
+            }).collect();
+
+            Game { id, draws }
+        }).collect()
+    }
+
+    fn part1(games: &Vec<Game>) -> i32 {
+        const MAX_RED: i32 = 12;
+        const MAX_GREEN: i32 = 13;
+        const MAX_BLUE: i32 = 14;
+
+        games.iter().map(|game| {
+            let Cubes { red, green, blue } = game.fewest_cubes();
+            if red > MAX_RED || green > MAX_GREEN || blue > MAX_BLUE {
+                0 // has invalid draw
+            }
+            else {
+                game.id
+            }
+        }).sum::<i32>()
+    }
+
+    fn part2(games: &Vec<Game>) -> i32 {
+        games.iter().map(|game| {
+            let cubes = game.fewest_cubes();
+            cubes.red * cubes.green * cubes.blue
+        }).sum::<i32>()
+    }
+}
+
+#[aoc::example(part1, "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+    Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+    Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+    Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green", 8)]
+#[aoc::part(day = 2, part = 1)]
+pub fn part1(input: &str) -> i32 {
+    Day2::part1(&Day2::parse(input))
+}
+
+#[aoc::example(part2, "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green", 48)]
+#[aoc::example(part2, "Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue", 12)]
+#[aoc::example(part2, "Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red", 1560)]
+#[aoc::example(part2, "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
+    Game 2: 1 blue, 2 green; 3 green, 4 blue, 1 red; 1 green, 1 blue
+    Game 3: 8 green, 6 blue, 20 red; 5 blue, 4 red, 13 green; 5 green, 1 red
+    Game 4: 1 green, 3 red, 6 blue; 3 green, 6 red; 3 green, 15 blue, 14 red
+    Game 5: 6 red, 1 blue, 3 green; 2 blue, 1 red, 2 green", 2286)]
+#[aoc::part(day = 2, part = 2)]
+pub fn part2(input: &str) -> i32 {
+    Day2::part2(&Day2::parse(input))
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use crate::{aoc, utils::{geom::Coord, grid::Grid}};
+
+use super::Solution;
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    type Input = Grid<char>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Grid<char> {
+        Grid::from(input)
+    }
+
+    fn part1(grid: &Grid<char>) -> i32 {
+        fn is_symbol(ch: char) -> bool {
+            !(ch.is_ascii_digit() || ch == '.')
+        }
+
+        grid.runs(char::is_ascii_digit)
+            .filter(|(span, _)| grid.span_neighbors(span).any(|(_, &c)| is_symbol(c)))
+            .map(|(_, digits)| String::from_iter(digits).parse::<i32>().unwrap())
+            .sum()
+    }
+
+    fn part2(grid: &Grid<char>) -> i32 {
+        // The numbers next to each gear
+        let mut gears = HashMap::<Coord, Vec<i32>>::new();
+
+        for (span, digits) in grid.runs(char::is_ascii_digit) {
+            let value = String::from_iter(digits).parse::<i32>().unwrap();
+            for (pos, _) in grid.span_neighbors(&span).filter(|&(_, &c)| c == '*') {
+                gears.entry(pos).or_default().push(value);
+            }
+        }
+
+        gears.values()
+            .filter(|values| values.len() == 2)
+            .map(|values| values.iter().product::<i32>())
+            .sum()
+    }
+}
+
+#[aoc::example(part1, "467..114..
+    ...*......
+    ..35..633.
+    ......#...
+    617*......
+    .....+.58.
+    ..592.....
+    ......755.
+    ...$.*....
+    .664.598..", 4361)]
+#[aoc::part(day = 3, part = 1)]
+pub fn part1(input: &str) -> i32 {
+    Day3::part1(&Day3::parse(input))
+}
+
+#[aoc::example(part2, "467..114..
+    ...*......
+    ..35..633.
+    ......#...
+    617*......
+    .....+.58.
+    ..592.....
+    ......755.
+    ...$.*....
+    .664.598..", 467835)]
+#[aoc::part(day = 3, part = 2)]
+pub fn part2(input: &str) -> i32 {
+    Day3::part2(&Day3::parse(input))
+}
@@ -0,0 +1,92 @@
+use std::collections::HashSet;
+
+use regex::Regex;
+
+use crate::aoc;
+
+use super::Solution;
+
+pub struct Card {
+    pub winners: HashSet<i32>,
+    pub ours: Vec<i32>,
+}
+
+impl Card {
+    pub fn num_wins(&self) -> usize {
+        self.ours.iter().filter(|n| self.winners.contains(n)).count()
+    }
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    type Input = Vec<Card>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Vec<Card> {
+        let card_re = Regex::new(r"Card\s+\d+: (.*?) \| (.*)").unwrap();
+        
+        fn get_numbers(nums: &str) -> impl Iterator<Item = i32> + '_ {
+            nums.split_ascii_whitespace().map(|num| num.parse().unwrap())
+        }
+
+        input.lines().map(|card| {
+            let caps = card_re.captures(card).unwrap();
+            let winners = get_numbers(caps.get(1).unwrap().as_str());
+            let ours = get_numbers(caps.get(2).unwrap().as_str());
+            Card { winners: HashSet::from_iter(winners), ours: ours.collect() }
+        }).collect()
+    }
+
+    fn part1(cards: &Vec<Card>) -> i32 {
+        cards.iter().map(|card| {
+            // Starting with 1, then we divide by 2 at the end which will floor to 0 when there are no matches
+            (1 << card.num_wins()) / 2
+        }).sum::<i32>()
+    }
+
+    fn part2(cards: &Vec<Card>) -> i32 {
+        struct CardInfo {
+            num_wins: usize,
+            num_cards: i32,
+        }
+
+        let mut card_info = cards.iter()
+            .map(|card| CardInfo { num_wins: card.num_wins(), num_cards: 1 })
+            .collect::<Vec<_>>();
+
+        for idx in 0..card_info.len() {
+            let CardInfo { num_wins, num_cards } = card_info[idx];
+            for add_idx in 0..num_wins {
+                if let Some(r) = card_info.get_mut(idx + add_idx + 1) {
+                    r.num_cards += num_cards;
+                }
+            }
+        }
+
+        card_info.iter().map(|info| info.num_cards).sum::<i32>()
+    }
+}
+
+#[aoc::example(part1, "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+    Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+    Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+    Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+    Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11", 13)]
+#[aoc::part(day = 4, part = 1)]
+pub fn part1(input: &str) -> i32 {
+    Day4::part1(&Day4::parse(input))
+}
+
+#[aoc::example(part2, "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
+    Card 2: 13 32 20 16 61 | 61 30 68 82 17 32 24 19
+    Card 3:  1 21 53 59 44 | 69 82 63 72 16 21 14  1
+    Card 4: 41 92 73 84 69 | 59 84 76 51 58  5 54 83
+    Card 5: 87 83 26 28 32 | 88 30 70 12 93 22 82 36
+    Card 6: 31 18 13 56 72 | 74 77 10 23 35 67 36 11", 30)]
+#[aoc::part(day = 4, part = 2)]
+pub fn part2(input: &str) -> i32 {
+    Day4::part2(&Day4::parse(input))
+}
@@ -0,0 +1,228 @@
+use itertools::Itertools;
+
+use crate::aoc;
+
+use super::Solution;
+
+/// Represents the bounds of an left-closed/right-open interval
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range(pub u64, pub u64);
+
+/// Maps `from..to` onto `dest..dest + (to - from)`
+#[derive(Debug, Clone, Copy)]
+pub struct Rule {
+    pub from: u64,
+    pub to: u64,
+    pub dest: u64,
+}
+
+#[derive(Debug, Clone)]
+pub struct Map(pub Vec<Rule>);
+
+impl Map {
+    pub fn apply(&self, num: u64) -> u64 {
+        for rule in &self.0 {
+            if num >= rule.from && num < rule.to {
+                return num - rule.from + rule.dest;
+            }
+        }
+        num
+    }
+
+    pub fn apply_to_range(&self, range: &Range) -> Vec<Range> {
+        let Map(rules) = self;
+        let intersections = rules.iter()
+            .filter_map(|rule| get_intersection(range, rule).map(|i| (rule, i)))
+            // Need to sort so we can find the non-intersecting ranges from sequential gaps
+            .sorted_by(|a, b| Ord::cmp(&a.1.0, &b.1.0));
+
+
+        //   [-------------------------)   range
+        //       [----)         [----)     intersections
+        //   [---)    [---------)    [-)   gaps
+
+        let mut start = range.0;
+        let end = range.1;
+        let mut mapped_ranges = Vec::new();
+
+        for (rule, intersection) in intersections {
+            // Add previous gap
+            mapped_ranges.push(Range(start, intersection.0));
+            // Add intersection (adjusted by mapping)
+            mapped_ranges.push(Range(
+                intersection.0 + rule.dest - rule.from,
+                intersection.1 + rule.dest - rule.from
+            ));
+            start = intersection.1;
+        }
+        // Add final gap
+        mapped_ranges.push(Range(start, end));
+
+        mapped_ranges
+    }
+}
+
+fn get_intersection(&Range(from, to): &Range, rule: &Rule) -> Option<Range> {
+    if from > rule.to || to < rule.from {
+        // Disjoint
+        None
+    }
+    else if from < rule.from {
+        if to < rule.to {
+            // Overlapping on left side
+            Some(Range(rule.from, to))
+        }
+        else {
+            // Fully contains rule
+            Some(Range(rule.from, rule.to))
+        }
+    }
+    else {
+        if rule.to < to {
+            // Overlapping on the right side
+            Some(Range(from, rule.to))
+        }
+        else {
+            // Fully contained by rule
+            Some(Range(from, to))
+        }
+    }
+}
+
+pub struct Almanac {
+    pub seeds: Vec<u64>,
+    pub maps: Vec<Map>,
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    type Input = Almanac;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Almanac {
+        let [seeds_str, map_strs @ ..] = &input.split("\n\n").collect_vec()[..] else { panic!() };
+
+        let seeds = seeds_str.split(" ").skip(1).map(|s| s.parse::<u64>().unwrap()).collect_vec();
+
+        let maps = map_strs.iter().map(|map_str| Map({
+            map_str.lines().skip(1).map(|rule_str| {
+                if let [dest, from, len] = rule_str
+                    .split_ascii_whitespace()
+                    .map(|s| s.parse().unwrap())
+                    .take(3)
+                    .collect_vec()[..]
+                {
+                    Rule { from, to: from + len, dest }
+                }
+                else {
+                    panic!()
+                }
+            }).collect_vec()
+        })).collect_vec();
+
+        Almanac { seeds, maps }
+    }
+
+    fn part1(almanac: &Almanac) -> u64 {
+        almanac.seeds.iter().map(|&seed| {
+            almanac.maps.iter().fold(seed, |num, map| map.apply(num))
+        }).min().unwrap()
+    }
+
+    fn part2(almanac: &Almanac) -> u64 {
+        let seed_ranges = almanac.seeds
+            .chunks(2)
+            .map(|pair| Range(pair[0], pair[0] + pair[1]))
+            .collect_vec();
+
+        fn run_map(ranges: Vec<Range>, map: &Map) -> Vec<Range> {
+            ranges.iter().flat_map(|range| map.apply_to_range(range))
+                .filter(|range| range.0 != range.1) // Filter out 0-length ranges
+                .collect_vec()
+        }
+
+        almanac.maps.iter().fold(seed_ranges, run_map)
+            .iter()
+            .map(|range| range.0)
+            .min()
+            .unwrap()
+    }
+}
+
+#[aoc::example(part1, "seeds: 79 14 55 13
+
+    seed-to-soil map:
+    50 98 2
+    52 50 48
+
+    soil-to-fertilizer map:
+    0 15 37
+    37 52 2
+    39 0 15
+
+    fertilizer-to-water map:
+    49 53 8
+    0 11 42
+    42 0 7
+    57 7 4
+
+    water-to-light map:
+    88 18 7
+    18 25 70
+
+    light-to-temperature map:
+    45 77 23
+    81 45 19
+    68 64 13
+
+    temperature-to-humidity map:
+    0 69 1
+    1 0 69
+
+    humidity-to-location map:
+    60 56 37
+    56 93 4", 35)]
+#[aoc::part(day = 5, part = 1)]
+pub fn part1(input: &str) -> u64 {
+    Day5::part1(&Day5::parse(input))
+}
+
+#[aoc::example(part2, "seeds: 79 14 55 13
+
+    seed-to-soil map:
+    50 98 2
+    52 50 48
+
+    soil-to-fertilizer map:
+    0 15 37
+    37 52 2
+    39 0 15
+
+    fertilizer-to-water map:
+    49 53 8
+    0 11 42
+    42 0 7
+    57 7 4
+
+    water-to-light map:
+    88 18 7
+    18 25 70
+
+    light-to-temperature map:
+    45 77 23
+    81 45 19
+    68 64 13
+
+    temperature-to-humidity map:
+    0 69 1
+    1 0 69
+
+    humidity-to-location map:
+    60 56 37
+    56 93 4", 46)]
+#[aoc::part(day = 5, part = 2)]
+pub fn part2(input: &str) -> u64 {
+    Day5::part2(&Day5::parse(input))
+}
@@ -0,0 +1,81 @@
+use itertools::Itertools;
+
+use crate::aoc;
+
+use super::Solution;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Race {
+    pub time: i64,
+    pub dist: i64,
+}
+
+impl Race {
+    pub fn count_ways_to_win(&self) -> i64 {
+        let Race { time, dist } = *self;
+        (0..time).filter(|t| t * (time - t) > dist).count() as i64
+    }
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    type Input = Vec<Race>;
+    type Output1 = i64;
+    type Output2 = i64;
+
+    fn parse(input: &str) -> Vec<Race> {
+        let (time_str, dist_str) = input.lines()
+            .map(|s| s
+                .split_ascii_whitespace()
+                .skip(1)
+                .map(|n| n.parse::<i64>().unwrap()))
+            .next_tuple()
+            .unwrap();
+
+        time_str.zip(dist_str).map(|(time, dist)| Race { time, dist }).collect_vec()
+    }
+
+    fn part1(races: &Vec<Race>) -> i64 {
+        races.iter().map(Race::count_ways_to_win).product::<i64>()
+    }
+
+    fn part2(races: &Vec<Race>) -> i64 {
+        // The spaces between numbers were bad kerning, it's actually just one race
+        let concat = |f: fn(&Race) -> i64| races.iter().map(|r| f(r).to_string()).join("").parse::<i64>().unwrap();
+        let (time, dist) = (concat(|r| r.time), concat(|r| r.dist));
+
+        // t_all: total time
+        // t_mov: moving time
+        // d: dist
+        // d = (t_all - t_mov) * t_mov
+        // d = (t_all * t_mov) - t_mov^2
+        // t_mov^2 - (t_all * t_mov) + d = 0
+        // t_mov = (t_all +- sqrt(t_all^2 - 4d)) / 2
+
+        // high(t_mov) - low(t_mov) = total range (inclusive)
+        // ceil the high and floor the low to get the true edges
+        // floor (or trunc) both to get +1 in the count so the high edge is included
+
+        let discrim = (time * time) - (4 * dist);
+        let sqrt_discrim = (discrim as f64).sqrt();
+        let high = (time as f64 + sqrt_discrim) / 2f64;
+        let low = (time as f64 - sqrt_discrim) / 2f64;
+
+        high as i64 - low as i64
+    }
+}
+
+#[aoc::example(part1, "Time:      7  15   30
+    Distance:  9  40  200", 288)]
+#[aoc::part(day = 6, part = 1)]
+pub fn part1(input: &str) -> i64 {
+    Day6::part1(&Day6::parse(input))
+}
+
+#[aoc::example(part2, "Time:      7  15   30
+    Distance:  9  40  200", 71503)]
+#[aoc::part(day = 6, part = 2)]
+pub fn part2(input: &str) -> i64 {
+    Day6::part2(&Day6::parse(input))
+}
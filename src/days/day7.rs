@@ -0,0 +1,162 @@
+use std::cmp::Ordering;
+
+use itertools::Itertools;
+use phf::phf_map;
+
+use crate::aoc;
+
+use super::Solution;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum HandType {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug, Clone)]
+pub struct Hand {
+    pub cards: [char; 5],
+    pub bid: i32,
+}
+
+/// Card values where a value of 0 is a joker, which counts as whichever card helps the hand most.
+type CardValues = phf::Map<char, u8>;
+
+const CARD_VALUES: CardValues = phf_map! {
+    '2' => 2,
+    '3' => 3,
+    '4' => 4,
+    '5' => 5,
+    '6' => 6,
+    '7' => 7,
+    '8' => 8,
+    '9' => 9,
+    'T' => 10,
+    'J' => 11,
+    'Q' => 12,
+    'K' => 13,
+    'A' => 14,
+};
+
+const JOKER_CARD_VALUES: CardValues = phf_map! {
+    'J' => 0,
+    '2' => 2,
+    '3' => 3,
+    '4' => 4,
+    '5' => 5,
+    '6' => 6,
+    '7' => 7,
+    '8' => 8,
+    '9' => 9,
+    'T' => 10,
+    'Q' => 11,
+    'K' => 12,
+    'A' => 13,
+};
+
+pub fn get_hand_type(cards: [u8; 5]) -> HandType {
+    let mut counts: [u8; 13] = [0; 13];
+    let mut jokers = 0;
+
+    for card in cards {
+        if card == 0 {
+            jokers += 1;
+        }
+        else {
+            counts[card as usize - 2] += 1;
+        }
+    }
+
+    let (most_freq, next_most_freq) = counts.iter()
+        .enumerate()
+        .sorted_by(|a, b| b.1.cmp(a.1))
+        .next_tuple()
+        .unwrap();
+
+    match most_freq.1 + jokers {
+        1 => HandType::HighCard,
+        2 => if *next_most_freq.1 == 2 { HandType::TwoPair } else { HandType::OnePair },
+        3 => if *next_most_freq.1 == 2 { HandType::FullHouse } else { HandType::ThreeOfAKind },
+        4 => HandType::FourOfAKind,
+        _ => HandType::FiveOfAKind,
+    }
+}
+
+fn compare_hands(cards1: &[u8; 5], cards2: &[u8; 5]) -> Ordering {
+    match get_hand_type(*cards1).cmp(&get_hand_type(*cards2)) {
+        Ordering::Greater => Ordering::Greater,
+        Ordering::Less => Ordering::Less,
+        Ordering::Equal => {
+            for i in 0..5 {
+                match cards1[i].cmp(&cards2[i]) {
+                    Ordering::Greater => return Ordering::Greater,
+                    Ordering::Less => return Ordering::Less,
+                    Ordering::Equal => {}
+                }
+            }
+            Ordering::Equal
+        }
+    }
+}
+
+fn total_winnings(hands: &[Hand], card_values: &CardValues) -> i32 {
+    hands.iter()
+        .map(|hand| (hand.cards.map(|c| *card_values.get(&c).unwrap()), hand.bid))
+        .sorted_by(|(cards1, _), (cards2, _)| compare_hands(cards1, cards2))
+        .enumerate()
+        .map(|(i, (_, bid))| (i as i32 + 1) * bid)
+        .sum::<i32>()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    type Input = Vec<Hand>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Vec<Hand> {
+        input.lines()
+            .map(|hand_str| {
+                let (cards_str, bid_str) = hand_str.split(' ').next_tuple().unwrap();
+                let mut cards_iter = cards_str.chars();
+                let cards = std::array::from_fn(|_| cards_iter.next().unwrap());
+                let bid = bid_str.parse().unwrap();
+                Hand { cards, bid }
+            })
+            .collect_vec()
+    }
+
+    fn part1(hands: &Vec<Hand>) -> i32 {
+        total_winnings(hands, &CARD_VALUES)
+    }
+
+    fn part2(hands: &Vec<Hand>) -> i32 {
+        total_winnings(hands, &JOKER_CARD_VALUES)
+    }
+}
+
+#[aoc::example(part1, "32T3K 765
+    T55J5 684
+    KK677 28
+    KTJJT 220
+    QQQJA 483", 6440)]
+#[aoc::part(day = 7, part = 1)]
+pub fn part1(input: &str) -> i32 {
+    Day7::part1(&Day7::parse(input))
+}
+
+#[aoc::example(part2, "32T3K 765
+    T55J5 684
+    KK677 28
+    KTJJT 220
+    QQQJA 483", 5905)]
+#[aoc::part(day = 7, part = 2)]
+pub fn part2(input: &str) -> i32 {
+    Day7::part2(&Day7::parse(input))
+}
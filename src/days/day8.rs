@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+use phf::phf_map;
+use regex::Regex;
+
+use crate::aoc;
+
+use super::Solution;
+
+/// A three character node name packed into an integer, first character in the highest byte.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Location(u32);
+
+impl Location {
+    pub fn new(s: &str) -> Location {
+        Location(s.chars().take(3).enumerate().map(|(i, c)| (c as u32) << ((2 - i) * 8)).sum())
+    }
+
+    pub const AAA: Location = Location((('A' as u32) << 16) + (('A' as u32) << 8) + ('A' as u32));
+    pub const ZZZ: Location = Location((('Z' as u32) << 16) + (('Z' as u32) << 8) + ('Z' as u32));
+
+    pub fn is_start(&self) -> bool {
+        self.0 & 0xFF == ('A' as u32)
+    }
+
+    pub fn is_end(&self) -> bool {
+        self.0 & 0xFF == ('Z' as u32)
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Choice { Left, Right }
+const PATH_MAP: phf::Map<char, Choice> = phf_map! { 'L' => Choice::Left, 'R' => Choice::Right };
+
+trait Directional<T> {
+    fn in_direction(&self, choice: Choice) -> T;
+}
+
+impl Directional<Location> for (Location, Location) {
+    fn in_direction(&self, choice: Choice) -> Location {
+        match choice {
+            Choice::Left => self.0,
+            Choice::Right => self.1,
+        }
+    }
+}
+
+pub struct Network {
+    pub route: Vec<Choice>,
+    pub map: HashMap<Location, (Location, Location)>,
+    /// Every node, in input order
+    pub locations: Vec<Location>,
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    type Input = Network;
+    type Output1 = u64;
+    type Output2 = u64;
+
+    fn parse(input: &str) -> Network {
+        let mut map = HashMap::<Location, (Location, Location)>::new();
+        let mut locations = Vec::<Location>::new();
+
+        let mut lines_iter = input.lines();
+        let route_str = lines_iter.next().unwrap();
+
+        let line_re = Regex::new(r"([A-Z0-9]{3}) = \(([A-Z0-9]{3}), ([A-Z0-9]{3})\)").unwrap();
+
+        for line in lines_iter.skip(1) {
+            let (at, left, right) = line_re.captures(line).unwrap()
+                .iter()
+                .skip(1)
+                .map(|cap| Location::new(cap.unwrap().as_str()))
+                .next_tuple()
+                .unwrap();
+            locations.push(at);
+            map.insert(at, (left, right));
+        }
+
+        let route = route_str.chars()
+            .map(|c| *PATH_MAP.get(&c).unwrap())
+            .collect_vec();
+
+        Network { route, map, locations }
+    }
+
+    fn part1(network: &Network) -> u64 {
+        let mut pos = Location::AAA;
+        let mut steps = 0;
+
+        for dir in network.route.iter().cycle() {
+            steps += 1;
+            pos = network.map.get(&pos).unwrap().in_direction(*dir);
+            if pos == Location::ZZZ {
+                return steps;
+            }
+        }
+
+        panic!()
+    }
+
+    fn part2(network: &Network) -> u64 {
+        let Network { route, map, locations } = network;
+        let route = route.iter().enumerate().collect_vec();
+
+        // While this doesn't handle the case where they all line up before one path cycles,
+        // the inputs are likely designed so that doesn't happen (since the problem would be trivial if so).
+        // Commented out is code to support a scenario where there are multiple exits as part of the cycle,
+        // though in practice the problem input was not designed that way.
+        #[derive(Debug)]
+        struct CycleInfo {
+            steps_until_exit_in_cycle: u64,
+            // exit_spacing: Vec<u64>,
+        }
+
+        let starting_locs = locations.iter().filter(|l| l.is_start()).copied().collect_vec();
+        let mut cycles = Vec::<CycleInfo>::new();
+
+        for loc in starting_locs {
+            let mut current_loc = loc;
+            let mut visited = HashMap::<(Location, usize), u64>::new();
+            let mut found_exits = Vec::<(Location, usize)>::new();
+
+            for (steps, &(steps_into_route, dir)) in (0..).zip(route.iter().cycle()) {
+                if let Some(steps_until_cycle) = visited.get(&(current_loc, steps_into_route)) {
+                    let found_exits_in_cycle = found_exits.iter()
+                        .filter(|ex| visited.get(ex).unwrap() >= steps_until_cycle)
+                        .collect_vec();
+                    
+                    let steps_until_exit_in_cycle = *visited.get(found_exits_in_cycle[0]).unwrap();
+                    
+                    // let mut exit_spacing = Vec::<u64>::new();
+                    // let mut steps_since_last_exit = steps_until_exit_in_cycle;
+                    // for exit in found_exits_in_cycle.iter().skip(1) {
+                    //     let &steps_to_exit = visited.get(exit).unwrap();
+                    //     exit_spacing.push(steps_to_exit - steps_since_last_exit);
+                    //     steps_since_last_exit = steps_to_exit;
+                    // }
+                    // exit_spacing.push(
+                    //     (steps - steps_since_last_exit) + (steps_until_exit_in_cycle - steps_until_cycle)
+                    // );
+
+                    cycles.push(CycleInfo { steps_until_exit_in_cycle, /* exit_spacing */ });
+                    break;
+                }
+
+                visited.insert((current_loc, steps_into_route), steps);
+                if current_loc.is_end() {
+                    found_exits.push((current_loc, steps_into_route));
+                }
+
+                current_loc = map.get(&current_loc).unwrap().in_direction(*dir);
+            }
+        }
+
+        // Because my input was designed such that there is only one cycle spacing
+        // which is the same as the number of steps until the first cycle,
+        // this last part can simply be implemented with LCM. I assume all inputs are
+        // structured that way, but if not, this may not work for some.
+        
+        fn gcd(a: u64, b: u64) -> u64 {
+            if b == 0 {
+                a
+            }
+            else {
+                gcd(b, a % b)
+            }
+        }
+
+        fn lcm(mut nums: impl Iterator<Item = u64>) -> Option<u64> {
+            if let Some(first) = nums.next() {
+                let second = lcm(nums)?;
+                Some((first * second) / gcd(first, second))
+            }
+            else {
+                Some(1)
+            }
+        }
+
+        lcm(cycles.iter().map(|c| c.steps_until_exit_in_cycle)).unwrap()
+    }
+}
+
+#[aoc::example(part1, "RL
+
+    AAA = (BBB, CCC)
+    BBB = (DDD, EEE)
+    CCC = (ZZZ, GGG)
+    DDD = (DDD, DDD)
+    EEE = (EEE, EEE)
+    GGG = (GGG, GGG)
+    ZZZ = (ZZZ, ZZZ)", 2)]
+#[aoc::example(part1, "LLR
+
+    AAA = (BBB, BBB)
+    BBB = (AAA, ZZZ)
+    ZZZ = (ZZZ, ZZZ)", 6)]
+#[aoc::part(day = 8, part = 1)]
+pub fn part1(input: &str) -> u64 {
+    Day8::part1(&Day8::parse(input))
+}
+
+#[aoc::example(part2, "LR
+
+    11A = (11B, XXX)
+    11B = (XXX, 11Z)
+    11Z = (11B, XXX)
+    22A = (22B, XXX)
+    22B = (22C, 22C)
+    22C = (22Z, 22Z)
+    22Z = (22B, 22B)
+    XXX = (XXX, XXX)", 6)]
+#[aoc::part(day = 8, part = 2)]
+pub fn part2(input: &str) -> u64 {
+    Day8::part2(&Day8::parse(input))
+}
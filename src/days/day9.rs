@@ -0,0 +1,61 @@
+use itertools::Itertools;
+
+use crate::aoc;
+
+use super::Solution;
+
+pub fn extrapolate_next(vals: &[i32]) -> i32 {
+    if vals.is_empty() {
+        return 0;
+    }
+    let next_row = vals.windows(2).map(|a| a[1] - a[0]).collect_vec();
+
+    vals.last().unwrap() + extrapolate_next(&next_row)
+}
+
+pub fn extrapolate_prev(vals: &[i32]) -> i32 {
+    if vals.is_empty() {
+        return 0;
+    }
+    let next_row = vals.windows(2).map(|a| a[1] - a[0]).collect_vec();
+
+    vals.first().unwrap() - extrapolate_prev(&next_row)
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    type Input = Vec<Vec<i32>>;
+    type Output1 = i32;
+    type Output2 = i32;
+
+    fn parse(input: &str) -> Vec<Vec<i32>> {
+        input.lines()
+            .map(|l| l.split(' ').map(|s| s.parse().unwrap()).collect_vec())
+            .collect_vec()
+    }
+
+    fn part1(histories: &Vec<Vec<i32>>) -> i32 {
+        histories.iter().map(|n| extrapolate_next(n)).sum::<i32>()
+    }
+
+    fn part2(histories: &Vec<Vec<i32>>) -> i32 {
+        histories.iter().map(|n| extrapolate_prev(n)).sum::<i32>()
+    }
+}
+
+#[aoc::example(part1, "0 3 6 9 12 15
+    1 3 6 10 15 21
+    10 13 16 21 30 45", 114)]
+#[aoc::part(day = 9, part = 1)]
+pub fn part1(input: &str) -> i32 {
+    Day9::part1(&Day9::parse(input))
+}
+
+#[aoc::example(part2, "0 3 6 9 12 15
+    1 3 6 10 15 21
+    10 13 16 21 30 45", 2)]
+#[aoc::part(day = 9, part = 2)]
+pub fn part2(input: &str) -> i32 {
+    Day9::part2(&Day9::parse(input))
+}
@@ -0,0 +1,38 @@
+//! Every day's solution as a library module.
+//!
+//! Each day exposes a [`Solution`] implementation, free `part1`/`part2` functions that take the raw
+//! puzzle input, and the types it parses the input into.
+
+use crate::AocResult;
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+
+/// A day's puzzle, split into parsing and the two parts.
+pub trait Solution {
+    type Input;
+    type Output1 : AocResult;
+    type Output2 : AocResult;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> Self::Output1;
+    fn part2(input: &Self::Input) -> Self::Output2;
+}
+
+/// Parses `input` once and solves both parts, formatted the same way the runner prints them.
+pub fn solve<S : Solution>(input: &str) -> (Option<String>, Option<String>) {
+    let parsed = S::parse(input);
+    (S::part1(&parsed).from_aoc(), S::part2(&parsed).from_aoc())
+}
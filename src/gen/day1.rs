@@ -0,0 +1,29 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+const WORDS: [&str; 9] = ["one", "two", "three", "four", "five", "six", "seven", "eight", "nine"];
+
+/// `size` lines of calibration text, each with at least one real digit and plenty of overlapping number words.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        (0..size.max(1)).map(|_| line(rng)).join("\n")
+    }
+}
+
+fn line(rng: &mut Rng) -> String {
+    let mut pieces = (0..rng.range(1..8))
+        .map(|_| match rng.range(0..3) {
+            0 => rng.range(1..10).to_string(),
+            1 => rng.choose(&WORDS).to_string(),
+            _ => (0..rng.range(1..4)).map(|_| (b'a' + rng.range(0..26) as u8) as char).collect(),
+        })
+        .collect_vec();
+
+    let at = rng.range(0..pieces.len() + 1);
+    pieces.insert(at, rng.range(1..10).to_string());
+    pieces.concat()
+}
@@ -0,0 +1,124 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+const PIPES: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+/// A `size` x `size` field with exactly one loop through `S`, surrounded by junk pipes.
+///
+/// The loop is the outline of a random region of cells that has no holes and never touches
+/// itself diagonally, which makes the outline a single simple cycle.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        let size = size.max(3);
+        // Region cells sit between pipe tiles, so there is one fewer in each direction
+        let cells = size - 1;
+        let mut region = vec![vec![false; cells]; cells];
+        let in_region = |region: &Vec<Vec<bool>>, x: isize, y: isize| {
+            x >= 0 && y >= 0 && (x as usize) < cells && (y as usize) < cells && region[y as usize][x as usize]
+        };
+
+        region[rng.range(0..cells)][rng.range(0..cells)] = true;
+        let target = rng.range(1..cells * cells / 2 + 2);
+
+        for _ in 0..target * 8 {
+            let (x, y) = (rng.range(0..cells) as isize, rng.range(0..cells) as isize);
+            if in_region(&region, x, y) {
+                continue;
+            }
+            let touches = [(0, 1), (1, 0), (0, -1), (-1, 0)].iter().any(|(dx, dy)| in_region(&region, x + dx, y + dy));
+            if !touches {
+                continue;
+            }
+
+            region[y as usize][x as usize] = true;
+            if !is_simple(&region) {
+                region[y as usize][x as usize] = false;
+            }
+        }
+
+        // Walk the corners between cells. A corner is on the loop when the cells around it differ.
+        let mut grid = vec![vec!['.'; size]; size];
+        let mut loop_tiles = Vec::new();
+        for y in 0..size as isize {
+            for x in 0..size as isize {
+                let up = in_region(&region, x - 1, y - 1) != in_region(&region, x, y - 1);
+                let down = in_region(&region, x - 1, y) != in_region(&region, x, y);
+                let left = in_region(&region, x - 1, y - 1) != in_region(&region, x - 1, y);
+                let right = in_region(&region, x, y - 1) != in_region(&region, x, y);
+                let tile = match (up, down, left, right) {
+                    (true, true, false, false) => '|',
+                    (false, false, true, true) => '-',
+                    (true, false, false, true) => 'L',
+                    (true, false, true, false) => 'J',
+                    (false, true, true, false) => '7',
+                    (false, true, false, true) => 'F',
+                    _ => continue,
+                };
+                grid[y as usize][x as usize] = tile;
+                loop_tiles.push((x as usize, y as usize));
+            }
+        }
+
+        let (sx, sy) = *rng.choose(&loop_tiles);
+        grid[sy][sx] = 'S';
+
+        // Fill everything else with junk, making sure nothing else connects to the start
+        for (y, row) in grid.iter_mut().enumerate() {
+            for (x, tile) in row.iter_mut().enumerate() {
+                if loop_tiles.contains(&(x, y)) {
+                    continue;
+                }
+                *tile = loop {
+                    let pipe = *rng.choose(&PIPES);
+                    let points_at_start =
+                        (x + 1 == sx && y == sy && "-LF".contains(pipe))
+                        || (x == sx + 1 && y == sy && "-J7".contains(pipe))
+                        || (x == sx && y + 1 == sy && "|7F".contains(pipe))
+                        || (x == sx && y == sy + 1 && "|LJ".contains(pipe));
+                    if !points_at_start { break pipe; }
+                };
+            }
+        }
+
+        grid.iter().map(|row| row.iter().collect::<String>()).join("\n")
+    }
+}
+
+/// No diagonal-only contacts and no holes.
+fn is_simple(region: &[Vec<bool>]) -> bool {
+    let cells = region.len();
+    let at = |x: usize, y: usize| region[y][x];
+
+    for y in 0..cells.saturating_sub(1) {
+        for x in 0..cells - 1 {
+            if at(x, y) == at(x + 1, y + 1) && at(x + 1, y) == at(x, y + 1) && at(x, y) != at(x + 1, y) {
+                return false;
+            }
+        }
+    }
+
+    // Every cell outside the region must be reachable from outside the grid
+    let mut outside = vec![vec![false; cells]; cells];
+    let mut stack = (0..cells)
+        .flat_map(|i| [(i, 0), (i, cells - 1), (0, i), (cells - 1, i)])
+        .filter(|&(x, y)| !at(x, y))
+        .collect_vec();
+    while let Some((x, y)) = stack.pop() {
+        if outside[y][x] {
+            continue;
+        }
+        outside[y][x] = true;
+        let neighbors = [(x.wrapping_sub(1), y), (x + 1, y), (x, y.wrapping_sub(1)), (x, y + 1)];
+        for (nx, ny) in neighbors {
+            if nx < cells && ny < cells && !at(nx, ny) && !outside[ny][nx] {
+                stack.push((nx, ny));
+            }
+        }
+    }
+
+    (0..cells).all(|y| (0..cells).all(|x| at(x, y) || outside[y][x]))
+}
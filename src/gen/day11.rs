@@ -0,0 +1,29 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+/// A `size` x `size` image with sparse galaxies and some guaranteed empty rows and columns.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        let size = size.max(2);
+        let empty_rows = (0..size).map(|_| rng.chance(0.2)).collect_vec();
+        let empty_cols = (0..size).map(|_| rng.chance(0.2)).collect_vec();
+
+        let mut grid = (0..size)
+            .map(|y| (0..size).map(|x| !empty_rows[y] && !empty_cols[x] && rng.chance(0.1)).collect_vec())
+            .collect_vec();
+
+        // Always have at least one pair
+        for _ in 0..2 {
+            let (x, y) = (rng.range(0..size), rng.range(0..size));
+            grid[y][x] = true;
+        }
+
+        grid.iter()
+            .map(|row| row.iter().map(|&galaxy| if galaxy { '#' } else { '.' }).collect::<String>())
+            .join("\n")
+    }
+}
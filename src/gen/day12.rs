@@ -0,0 +1,39 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+/// `size` spring rows of up to `size` cells, capped at the real input's 20 so brute force
+/// still finishes. Each row is derived from a concrete arrangement, so it always has at
+/// least one solution.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        (0..size.max(1)).map(|_| row(rng, size.clamp(1, 20))).join("\n")
+    }
+}
+
+fn row(rng: &mut Rng, size: usize) -> String {
+    let len = rng.range(1..size + 1);
+    let mut cells = (0..len).map(|_| if rng.chance(0.5) { '#' } else { '.' }).collect_vec();
+    if !cells.contains(&'#') {
+        cells[rng.range(0..len)] = '#';
+    }
+
+    let spans = cells.iter()
+        .group_by(|&&c| c)
+        .into_iter()
+        .filter(|(c, _)| *c == '#')
+        .map(|(_, run)| run.count())
+        .join(",");
+
+    let unknown_rate = rng.range(0..101) as f64 / 100.0;
+    for cell in cells.iter_mut() {
+        if rng.chance(unknown_rate) {
+            *cell = '?';
+        }
+    }
+
+    format!("{} {}", cells.iter().collect::<String>(), spans)
+}
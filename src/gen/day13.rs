@@ -0,0 +1,94 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+/// `size` patterns, each with exactly one perfect line of reflection and exactly one other
+/// line that would be perfect if not for a single smudge.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        (0..size.max(1)).map(|_| pattern(rng)).join("\n\n")
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Axis {
+    Horizontal(usize),
+    Vertical(usize),
+}
+
+fn pattern(rng: &mut Rng) -> String {
+    loop {
+        let (width, height) = (rng.range(5..18), rng.range(5..18));
+        let axes = (1..height).map(Axis::Horizontal).chain((1..width).map(Axis::Vertical)).collect_vec();
+        let perfect = *rng.choose(&axes);
+        let smudged = *rng.choose(&axes);
+        if perfect == smudged {
+            continue;
+        }
+
+        // Cells that must be equal are merged, except for the one pair that carries the smudge
+        let mut parent = (0..width * height).collect_vec();
+        fn find(parent: &mut [usize], i: usize) -> usize {
+            if parent[i] != i {
+                parent[i] = find(parent, parent[i]);
+            }
+            parent[i]
+        }
+
+        for (a, b) in mirrored_pairs(perfect, width, height) {
+            let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+            parent[ra] = rb;
+        }
+
+        let smudged_pairs = mirrored_pairs(smudged, width, height);
+        let (smudge_a, smudge_b) = *rng.choose(&smudged_pairs);
+        for &(a, b) in &smudged_pairs {
+            if (a, b) != (smudge_a, smudge_b) {
+                let (ra, rb) = (find(&mut parent, a), find(&mut parent, b));
+                parent[ra] = rb;
+            }
+        }
+
+        let (ra, rb) = (find(&mut parent, smudge_a), find(&mut parent, smudge_b));
+        if ra == rb {
+            continue;
+        }
+
+        let mut colors = vec![None; width * height];
+        colors[ra] = Some(true);
+        colors[rb] = Some(false);
+        let cells = (0..width * height)
+            .map(|i| {
+                let root = find(&mut parent, i);
+                *colors[root].get_or_insert_with(|| rng.chance(0.5))
+            })
+            .collect_vec();
+
+        // Accidental extra reflections are possible on small patterns, so double check
+        let defects = axes.iter()
+            .map(|&axis| mirrored_pairs(axis, width, height).iter().filter(|&&(a, b)| cells[a] != cells[b]).count())
+            .collect_vec();
+        if defects.iter().filter(|&&d| d == 0).count() != 1 || defects.iter().filter(|&&d| d == 1).count() != 1 {
+            continue;
+        }
+
+        return cells.chunks(width)
+            .map(|row| row.iter().map(|&rock| if rock { '#' } else { '.' }).collect::<String>())
+            .join("\n");
+    }
+}
+
+/// Index pairs of cells that a reflection across `axis` maps onto each other.
+fn mirrored_pairs(axis: Axis, width: usize, height: usize) -> Vec<(usize, usize)> {
+    match axis {
+        Axis::Horizontal(refl_y) => (refl_y..height).zip((0..refl_y).rev())
+            .flat_map(|(y1, y2)| (0..width).map(move |x| (x + y1 * width, x + y2 * width)))
+            .collect_vec(),
+        Axis::Vertical(refl_x) => (refl_x..width).zip((0..refl_x).rev())
+            .flat_map(|(x1, x2)| (0..height).map(move |y| (x1 + y * width, x2 + y * width)))
+            .collect_vec(),
+    }
+}
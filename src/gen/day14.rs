@@ -0,0 +1,24 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+/// A `size` x `size` platform of round rocks, cube rocks and empty space.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        let size = size.max(1);
+        (0..size)
+            .map(|_| {
+                (0..size)
+                    .map(|_| match rng.range(0..10) {
+                        0..=1 => 'O',
+                        2 => '#',
+                        _ => '.',
+                    })
+                    .collect::<String>()
+            })
+            .join("\n")
+    }
+}
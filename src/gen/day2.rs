@@ -0,0 +1,26 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+/// `size` games of up to six draws. Counts hover around the part one limits so both outcomes occur.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        (1..=size.max(1))
+            .map(|id| {
+                let draws = (0..rng.range(1..7))
+                    .map(|_| {
+                        let mut colors = ["red", "green", "blue"];
+                        rng.shuffle(&mut colors);
+                        colors[..rng.range(1..4)].iter()
+                            .map(|color| format!("{} {}", rng.range(1..21), color))
+                            .join(", ")
+                    })
+                    .join("; ");
+                format!("Game {}: {}", id, draws)
+            })
+            .join("\n")
+    }
+}
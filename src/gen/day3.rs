@@ -0,0 +1,38 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '=', '@', '%', '&', '-'];
+
+/// A `size` x `size` engine schematic with scattered part numbers and symbols.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        let size = size.max(2);
+        let mut rows = vec![vec!['.'; size]; size];
+
+        for row in rows.iter_mut() {
+            let mut x = rng.range(0..3);
+            while x < size {
+                if rng.chance(0.15) {
+                    row[x] = *rng.choose(&SYMBOLS);
+                    x += 2;
+                }
+                else if rng.chance(0.3) {
+                    let number = rng.range(1..1000).to_string();
+                    for (offset, digit) in number.chars().enumerate().take(size - x) {
+                        row[x + offset] = digit;
+                    }
+                    x += number.len() + 1;
+                }
+                else {
+                    x += 1;
+                }
+            }
+        }
+
+        rows.iter().map(|row| row.iter().collect::<String>()).join("\n")
+    }
+}
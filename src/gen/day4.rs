@@ -0,0 +1,41 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+const WINNERS: usize = 10;
+const OURS: usize = 25;
+
+/// `size` scratchcards. Later cards win less often so that card copies never run past the end
+/// of the table or grow without bound.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        let size = size.max(1);
+        let width = size.to_string().len();
+
+        (1..=size)
+            .map(|id| {
+                let mut numbers = (1..100).collect_vec();
+                rng.shuffle(&mut numbers);
+
+                let max_matches = (size - id).min(WINNERS);
+                let matches = if rng.chance(0.5) { 0 } else { rng.range(0..max_matches + 1) };
+
+                let winners = &numbers[..WINNERS];
+                let mut ours = numbers[WINNERS..WINNERS + OURS - matches].to_vec();
+                ours.extend_from_slice(&winners[..matches]);
+                rng.shuffle(&mut ours);
+
+                format!(
+                    "Card {:>width$}: {} | {}",
+                    id,
+                    winners.iter().map(|n| format!("{:>2}", n)).join(" "),
+                    ours.iter().map(|n| format!("{:>2}", n)).join(" "),
+                    width = width,
+                )
+            })
+            .join("\n")
+    }
+}
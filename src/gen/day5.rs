@@ -0,0 +1,58 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+const STAGES: [&str; 8] = ["seed", "soil", "fertilizer", "water", "light", "temperature", "humidity", "location"];
+const LIMIT: u64 = 1 << 32;
+
+/// An almanac with `size` rules per map and `size` seed ranges. Seed ranges are placed to straddle
+/// rule boundaries, and each map's rules tile part of the number line with occasional gaps.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        let size = size.max(1);
+
+        let maps = STAGES.iter().tuple_windows()
+            .map(|(from, to)| {
+                let mut start = rng.below(LIMIT / 4);
+                let rules = (0..size)
+                    .map(|_| {
+                        if rng.chance(0.3) {
+                            start += rng.below(LIMIT / (size as u64 * 8)) + 1;
+                        }
+                        let len = rng.below(LIMIT / (size as u64 * 4)) + 1;
+                        let dest = rng.below(LIMIT);
+                        let rule = (dest, start, len);
+                        start += len;
+                        rule
+                    })
+                    .collect_vec();
+                (from, to, rules)
+            })
+            .collect_vec();
+
+        // Seed ranges start inside the first map's rules so they overlap rule edges
+        let (_, _, first_rules) = &maps[0];
+        let seeds = (0..size)
+            .map(|_| {
+                let &(_, from, len) = rng.choose(first_rules);
+                let seed = from + rng.below(len);
+                (seed, rng.below(LIMIT / (size as u64 * 2)) + 1)
+            })
+            .map(|(seed, len)| format!("{} {}", seed, len))
+            .join(" ");
+
+        let maps_str = maps.iter()
+            .map(|(from, to, rules)| {
+                let rules_str = rules.iter()
+                    .map(|(dest, from, len)| format!("{} {} {}", dest, from, len))
+                    .join("\n");
+                format!("{}-to-{} map:\n{}", from, to, rules_str)
+            })
+            .join("\n\n");
+
+        format!("seeds: {}\n\n{}", seeds, maps_str)
+    }
+}
@@ -0,0 +1,27 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+/// Up to four races, each of which can be won. The solutions multiply and concatenate the
+/// numbers, so the input can't grow past the real input's shape without overflowing.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        let races = (0..size.clamp(1, 4))
+            .map(|_| {
+                let time = rng.range(2..100) as u64;
+                let best = (time / 2) * (time - time / 2);
+                (time, rng.below(best))
+            })
+            .collect_vec();
+
+        let width = races.iter().map(|(_, dist)| dist.to_string().len()).max().unwrap().max(2);
+        format!(
+            "Time:     {}\nDistance: {}",
+            races.iter().map(|(time, _)| format!("{:>width$}", time, width = width)).join(" "),
+            races.iter().map(|(_, dist)| format!("{:>width$}", dist, width = width)).join(" "),
+        )
+    }
+}
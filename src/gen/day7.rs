@@ -0,0 +1,33 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+const CARDS: [char; 13] = ['2', '3', '4', '5', '6', '7', '8', '9', 'T', 'J', 'Q', 'K', 'A'];
+
+/// `size` distinct hands with bids. Hands are biased towards repeated cards and jokers so every
+/// hand type shows up.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        let mut seen = std::collections::HashSet::new();
+        let mut hands = Vec::new();
+
+        while hands.len() < size.max(1) {
+            let pool = (0..rng.range(1..6)).map(|_| *rng.choose(&CARDS)).collect_vec();
+            let hand = (0..5)
+                .map(|_| if rng.chance(0.1) { 'J' } else { *rng.choose(&pool) })
+                .collect::<String>();
+            if seen.insert(hand.clone()) {
+                hands.push(format!("{} {}", hand, rng.range(1..1001)));
+            }
+            // There are only so many distinct hands
+            if seen.len() >= CARDS.len().pow(5) {
+                break;
+            }
+        }
+
+        hands.join("\n")
+    }
+}
@@ -0,0 +1,64 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+/// A network with a route of up to `size + 2` turns and up to `size` ghosts, at most six.
+///
+/// Each ghost runs around a private loop whose only exit is reached after exactly the loop's
+/// length, which is a multiple of the route length. This is the structure the real inputs have.
+/// One ghost always starts at `AAA` and exits at `ZZZ`.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        const PRIMES: [usize; 8] = [2, 3, 5, 7, 11, 13, 17, 19];
+
+        let route = (0..rng.range(2..size + 3)).map(|_| *rng.choose(&['L', 'R'])).collect::<String>();
+        let num_ghosts = rng.range(1..size.clamp(1, 6) + 1);
+
+        let mut primes = PRIMES.to_vec();
+        rng.shuffle(&mut primes);
+
+        let mut used = HashSet::from(["AAA".to_string(), "ZZZ".to_string()]);
+        let mut name = |rng: &mut Rng, last: char| loop {
+            let letters = [rng.range(0..26), rng.range(0..26)].map(|i| (b'A' + i as u8) as char);
+            let candidate = format!("{}{}{}", letters[0], letters[1], last);
+            if used.insert(candidate.clone()) {
+                break candidate;
+            }
+        };
+
+        let mut lines = Vec::new();
+
+        for (ghost, &prime) in primes.iter().take(num_ghosts).enumerate() {
+            let (start, exit) = match ghost {
+                0 => ("AAA".to_string(), "ZZZ".to_string()),
+                _ => (name(rng, 'A'), name(rng, 'Z')),
+            };
+
+            // start -> n1 -> ... -> n(len - 1) -> exit -> n1
+            let len = route.len() * prime;
+            let middle = (1..len)
+                .map(|_| {
+                    let last = loop {
+                        let c = (b'A' + rng.range(0..26) as u8) as char;
+                        if c != 'A' && c != 'Z' { break c; }
+                    };
+                    name(rng, last)
+                })
+                .collect_vec();
+
+            let nodes = middle.iter().chain([&exit]).collect_vec();
+            lines.push(format!("{} = ({}, {})", start, nodes[0], nodes[0]));
+            for (from, to) in nodes.iter().zip(nodes.iter().skip(1).chain([&nodes[0]])) {
+                lines.push(format!("{} = ({}, {})", from, to, to));
+            }
+        }
+
+        rng.shuffle(&mut lines);
+        format!("{}\n\n{}", route, lines.join("\n"))
+    }
+}
@@ -0,0 +1,21 @@
+use itertools::Itertools;
+
+use crate::{gen::InputGenerator, utils::rng::Rng};
+
+/// `size` sequences of 21 values, each produced by a polynomial of degree at most five.
+pub struct Generator;
+
+impl InputGenerator for Generator {
+    fn generate(&self, size: usize, seed: u64) -> String {
+        let rng = &mut Rng::new(seed);
+        (0..size.max(1))
+            .map(|_| {
+                let degree = rng.range(0..6);
+                let coefficients = (0..=degree).map(|_| rng.range_i64(-5..6)).collect_vec();
+                (0..21i64)
+                    .map(|x| coefficients.iter().rev().fold(0, |acc, c| acc * x + c))
+                    .join(" ")
+            })
+            .join("\n")
+    }
+}
@@ -0,0 +1,44 @@
+//! Random valid puzzle inputs, for fuzzing solution variants against each other and for stress testing.
+
+pub mod day1;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+
+/// Produces puzzle inputs that satisfy the guarantees of the real input.
+pub trait InputGenerator {
+    /// `size` scales the input (number of lines, grid side length, ...), and the same
+    /// `size` and `seed` always produce the same input.
+    fn generate(&self, size: usize, seed: u64) -> String;
+}
+
+/// Looks up the input generator for a day, if there is one.
+pub fn for_day(day: u32) -> Option<Box<dyn InputGenerator>> {
+    match day {
+        1 => Some(Box::new(day1::Generator)),
+        2 => Some(Box::new(day2::Generator)),
+        3 => Some(Box::new(day3::Generator)),
+        4 => Some(Box::new(day4::Generator)),
+        5 => Some(Box::new(day5::Generator)),
+        6 => Some(Box::new(day6::Generator)),
+        7 => Some(Box::new(day7::Generator)),
+        8 => Some(Box::new(day8::Generator)),
+        9 => Some(Box::new(day9::Generator)),
+        10 => Some(Box::new(day10::Generator)),
+        11 => Some(Box::new(day11::Generator)),
+        12 => Some(Box::new(day12::Generator)),
+        13 => Some(Box::new(day13::Generator)),
+        14 => Some(Box::new(day14::Generator)),
+        _ => None,
+    }
+}
@@ -1,5 +1,27 @@
+extern crate self as aoc_2023;
+
 pub mod utils;
+pub mod runner;
+pub mod gen;
+pub mod days;
+
+/// Attribute API for declaring parts and examples, e.g. `#[aoc::part(day = 5, part = 1)]`.
+pub mod aoc {
+    pub use aoc_macros::{example, part};
+}
+
+#[doc(hidden)]
+pub mod __private {
+    pub use indoc::indoc;
+    pub use inventory;
+}
+
+fluid_let::fluid_let!(pub static DEBUG: bool = false);
+
+// Set by the runner's `record` command, see `utils::grid::FrameRecorder`
+fluid_let::fluid_let!(pub static RECORD: bool = false);
 
+#[allow(clippy::wrong_self_convention)]
 pub trait AocResult {
     fn from_aoc(&self) -> Option<String>;
 }
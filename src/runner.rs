@@ -0,0 +1,269 @@
+use std::{fs, path::Path, str::FromStr, time::{Duration, Instant}};
+
+use colored::Colorize;
+use itertools::Itertools;
+
+use crate::{gen, utils::{fuzz, grid}, DEBUG, RECORD};
+
+/// A part function registered with `#[aoc::part]`.
+pub struct Part {
+    pub day: u32,
+    pub part: u32,
+    pub name: &'static str,
+    /// `None` for the primary implementation of a part, otherwise the name of the alternative.
+    pub variant: Option<&'static str>,
+    pub run: fn(&str) -> Option<String>,
+}
+
+/// An example registered with `#[aoc::example]`.
+pub struct Example {
+    pub day: u32,
+    pub part: u32,
+    /// Source line of the attribute, used to run examples in declaration order
+    pub line: u32,
+    pub input: &'static str,
+    pub expected: fn() -> String,
+}
+
+inventory::collect!(Part);
+inventory::collect!(Example);
+
+/// All variants registered for a single part of a day, primary first.
+struct PartGroup<'a> {
+    part: u32,
+    variants: Vec<&'a Part>,
+}
+
+fn part_groups(day: u32) -> Vec<PartGroup<'static>> {
+    inventory::iter::<Part>.into_iter()
+        .filter(|p| p.day == day)
+        .sorted_by_key(|p| (p.part, p.variant.is_some(), p.name))
+        .group_by(|p| p.part)
+        .into_iter()
+        .map(|(part, variants)| PartGroup { part, variants: variants.collect_vec() })
+        .collect_vec()
+}
+
+fn read_input(day: u32) -> Option<String> {
+    let path = format!("./inputs/day{}.txt", day);
+    println!("Reading {}...", path);
+    match std::fs::read_to_string(Path::new(&path)) {
+        Err(err) => {
+            println!("Failed to read {}: {}", path, err);
+            None
+        }
+        Ok(input) => Some(input),
+    }
+}
+
+fn timed(part: &Part, input: &str) -> (Option<String>, Duration) {
+    let start = Instant::now();
+    let result = (part.run)(input);
+    (result, start.elapsed())
+}
+
+/// Runs every example and then every part registered for `day` against `./inputs/day{day}.txt`.
+///
+/// Passing `bench [iterations]` on the command line benchmarks each variant instead, and
+/// `fuzz [--seed S] [--iterations N] [--max-size N]` checks the variants against each other
+/// on generated inputs. A failing case is minimized and saved under `./fuzz`, and can be replayed
+/// with `fuzz --input PATH`, or regenerated unminimized with `fuzz --seed S --size N`.
+/// `record` solves as usual but lets the primary parts save animations of the real input with
+/// `FrameRecorder`.
+///
+/// Exits with a nonzero status if an example fails or a variant disagrees with the primary.
+pub fn run_day(day: u32) {
+    let args = std::env::args().skip(1).collect_vec();
+    let passed = match args.first().map(String::as_str) {
+        Some("bench") => {
+            let iterations = args.get(1).and_then(|n| n.parse().ok()).unwrap_or(10);
+            bench_day(day, iterations);
+            true
+        }
+        Some("fuzz") => fuzz_day(day, &args[1..]),
+        Some("record") => solve_day(day, true),
+        _ => solve_day(day, false),
+    };
+
+    if !passed {
+        std::process::exit(1);
+    }
+}
+
+fn flag<T : FromStr>(args: &[String], name: &str) -> Option<T> {
+    args.iter()
+        .position(|a| a == name)
+        .and_then(|i| args.get(i + 1))
+        .and_then(|v| v.parse().ok())
+}
+
+/// Whether every example passed and every variant agreed with its primary. With `record`, the
+/// primaries may record animations while solving the real input, but not the examples.
+fn solve_day(day: u32, record: bool) -> bool {
+    let groups = part_groups(day);
+    let mut passed = true;
+
+    let examples = inventory::iter::<Example>.into_iter()
+        .filter(|e| e.day == day)
+        .sorted_by_key(|e| (e.part, e.line));
+
+    for example in examples {
+        for group in groups.iter().filter(|g| g.part == example.part) {
+            passed &= run_example(group, example);
+        }
+    }
+
+    let Some(input) = read_input(day) else { return passed };
+
+    for group in &groups {
+        let (primary, variants) = group.variants.split_first().unwrap();
+        let (expected, elapsed) = RECORD.set(record, || timed(primary, &input));
+        match &expected {
+            None => println!("[{}] Not implemented.", primary.name),
+            Some(result) => println!("[{}] SOLUTION: {} ({:.2?})", primary.name, result, elapsed),
+        }
+        save_recordings(primary);
+
+        for part in variants {
+            let (actual, elapsed) = timed(part, &input);
+            if actual == expected {
+                println!("[{}] Agrees with {} ({:.2?})", part.name, primary.name, elapsed);
+            }
+            else {
+                let message = format!(
+                    "[{}] MISMATCH: returned {} but {} returned {}",
+                    part.name,
+                    actual.as_deref().unwrap_or("nothing"),
+                    primary.name,
+                    expected.as_deref().unwrap_or("nothing"),
+                );
+                println!("{}", message.red());
+                passed = false;
+            }
+        }
+    }
+
+    passed
+}
+
+/// Saves whatever `part` recorded. A failed save is reported but doesn't count against the part.
+fn save_recordings(part: &Part) {
+    for recording in grid::take_recordings() {
+        match recording.save() {
+            Ok(path) => println!("[{}] Saved recording to {}", part.name, path.display()),
+            Err(err) => println!("{}", format!("[{}] Failed to save recording: {}", part.name, err).red()),
+        }
+    }
+}
+
+/// Whether every implemented variant produced the expected answer.
+fn run_example(group: &PartGroup, example: &Example) -> bool {
+    let expected = (example.expected)();
+    let mut passed = true;
+
+    for part in &group.variants {
+        let actual = DEBUG.set(true, || (part.run)(example.input));
+
+        match actual {
+            None => println!("[{}] EX: Not implemented, Skipping.", part.name),
+            Some(actual) => if actual == expected {
+                println!("[{}] EX: Passed.", part.name);
+            }
+            else {
+                println!("[{}] EX: Expected {} but got {}.", part.name, expected, actual);
+                passed = false;
+            }
+        }
+    }
+
+    passed
+}
+
+fn bench_day(day: u32, iterations: u32) {
+    let Some(input) = read_input(day) else { return };
+
+    for group in part_groups(day) {
+        println!("[part{}] {} iteration(s)", group.part, iterations);
+
+        let timings = group.variants.iter()
+            .map(|part| {
+                let times = (0..iterations).map(|_| timed(part, &input).1).collect_vec();
+                let mean = times.iter().sum::<Duration>() / iterations.max(1);
+                let min = times.iter().min().copied().unwrap_or_default();
+                (part, mean, min)
+            })
+            .collect_vec();
+
+        let fastest = timings.iter().map(|&(_, mean, _)| mean).min().unwrap_or_default();
+        let name_width = timings.iter().map(|(part, ..)| part.name.len()).max().unwrap_or(0);
+
+        for (part, mean, min) in timings {
+            println!(
+                "    {:width$}  mean {:>10.2?}  min {:>10.2?}  {:>6.2}x",
+                part.name,
+                mean,
+                min,
+                mean.as_secs_f64() / fastest.as_secs_f64().max(f64::EPSILON),
+                width = name_width,
+            );
+        }
+    }
+}
+
+/// Whether every variant agreed with its primary on every generated input.
+fn fuzz_day(day: u32, args: &[String]) -> bool {
+    let Some(generator) = gen::for_day(day) else {
+        println!("No input generator for day {}.", day);
+        return true;
+    };
+
+    let mut config = fuzz::Config::default();
+    if let Some(seed) = flag(args, "--seed") { config.seed = seed; }
+    if let Some(iterations) = flag(args, "--iterations") { config.iterations = iterations; }
+    if let Some(max_size) = flag(args, "--max-size") { config.max_size = max_size; }
+    // A saved case from `--input`, or one regenerated from the seed with `--size`
+    let replay = if let Some(path) = flag::<String>(args, "--input") {
+        match fs::read_to_string(Path::new(&path)) {
+            Err(err) => {
+                println!("Failed to read {}: {}", path, err);
+                return false;
+            }
+            Ok(input) => Some((path, input)),
+        }
+    }
+    else {
+        flag::<usize>(args, "--size")
+            .map(|size| (format!("seed {} with size {}", config.seed, size), fuzz::replay(&*generator, config.seed, size)))
+    };
+    let mut passed = true;
+
+    for group in part_groups(day) {
+        let (primary, variants) = group.variants.split_first().unwrap();
+
+        for variant in variants {
+            let label = format!("[{} vs {}]", primary.name, variant.name);
+
+            if let Some((source, input)) = &replay {
+                println!("{} Replaying {}:\n{}", label, source, input);
+                println!("{}: {}", primary.name, fuzz::run_caught(primary.run, input));
+                println!("{}: {}", variant.name, fuzz::run_caught(variant.run, input));
+                continue;
+            }
+
+            match fuzz::differential(&*generator, primary.run, variant.run, &config) {
+                Ok(cases) => println!("{} Agreed on {} generated inputs.", label, cases),
+                Err(failure) => {
+                    println!("{}", format!("{} {}", label, failure).red());
+                    let path = format!("./fuzz/day{}-{}.txt", day, variant.name);
+                    match fs::create_dir_all("./fuzz").and_then(|_| fs::write(&path, &failure.minimized)) {
+                        Ok(()) => println!("Replay the minimized input with `fuzz --input {}`", path),
+                        Err(err) => println!("Failed to save the minimized input to {}: {}", path, err),
+                    }
+                    passed = false;
+                }
+            }
+        }
+    }
+
+    passed
+}
@@ -0,0 +1,155 @@
+use std::{any::Any, fmt::Display, panic::{self, AssertUnwindSafe}};
+
+use crate::gen::InputGenerator;
+
+use super::rng::Rng;
+
+/// Settings for a differential fuzzing run.
+#[derive(Debug, Clone)]
+pub struct Config {
+    pub iterations: u32,
+    pub seed: u64,
+    /// Sizes are ramped from 1 up to this value over the run
+    pub max_size: usize,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config { iterations: 5000, seed: 0, max_size: 12 }
+    }
+}
+
+/// What a function did with a fuzzed input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Returned(Option<String>),
+    Panicked(String),
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Outcome::Returned(Some(value)) => write!(f, "{}", value),
+            Outcome::Returned(None) => write!(f, "nothing"),
+            Outcome::Panicked(message) => write!(f, "panic ({})", message),
+        }
+    }
+}
+
+/// A case on which the two functions disagreed.
+#[derive(Debug, Clone)]
+pub struct Failure {
+    /// `seed` and `size` regenerate `generated` exactly, see [`replay`]
+    pub seed: u64,
+    pub size: usize,
+    pub generated: String,
+    /// The generated input with as many lines removed as possible while still failing
+    pub minimized: String,
+    pub left: Outcome,
+    pub right: Outcome,
+}
+
+impl Display for Failure {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Mismatch with seed {} and size {}", self.seed, self.size)?;
+        writeln!(f, "Replay the original, unminimized input with `fuzz --seed {} --size {}`", self.seed, self.size)?;
+        writeln!(f, "Minimized input:")?;
+        writeln!(f, "{}", self.minimized)?;
+        writeln!(f, "Left:  {}", self.left)?;
+        write!(f, "Right: {}", self.right)
+    }
+}
+
+/// Regenerates the input for a single case.
+pub fn replay(generator: &(impl InputGenerator + ?Sized), seed: u64, size: usize) -> String {
+    generator.generate(size, seed)
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload.downcast_ref::<&str>().map(|s| s.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown payload".to_string())
+}
+
+/// Runs `f`, turning a panic into an `Outcome` instead of unwinding further.
+pub fn run_caught(f: impl Fn(&str) -> Option<String>, input: &str) -> Outcome {
+    match panic::catch_unwind(AssertUnwindSafe(|| f(input))) {
+        Ok(result) => Outcome::Returned(result),
+        Err(payload) => Outcome::Panicked(panic_message(&*payload)),
+    }
+}
+
+/// Feeds generated inputs to `left` and `right` until they disagree, then shrinks the failing case.
+///
+/// Returns the number of cases checked if no disagreement was found.
+pub fn differential<G, L, R>(generator: &G, left: L, right: R, config: &Config) -> Result<u32, Box<Failure>>
+    where G : InputGenerator + ?Sized,
+          L : Fn(&str) -> Option<String>,
+          R : Fn(&str) -> Option<String>,
+{
+    let check = |input: &str| {
+        let l = run_caught(&left, input);
+        let r = run_caught(&right, input);
+        (l != r).then_some((l, r))
+    };
+
+    let run = || {
+        for i in 0..config.iterations {
+            let seed = Rng::derive_seed(config.seed, i as u64);
+            let size = 1 + (i as usize * config.max_size.max(1)) / config.iterations as usize;
+            let input = generator.generate(size, seed);
+
+            if check(&input).is_some() {
+                return Err(Box::new(shrink(generator, &check, seed, size)));
+            }
+        }
+        Ok(config.iterations)
+    };
+
+    // Panics are expected results here, so keep them from spamming stderr while we run. The run is
+    // caught as a whole so the hook is put back even if the generator itself panics.
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let result = panic::catch_unwind(AssertUnwindSafe(run));
+    panic::set_hook(hook);
+
+    result.unwrap_or_else(|payload| panic!("Fuzzing failed outside the functions under test: {}", panic_message(&*payload)))
+}
+
+fn shrink<G, C>(generator: &G, check: &C, seed: u64, size: usize) -> Failure
+    where G : InputGenerator + ?Sized,
+          C : Fn(&str) -> Option<(Outcome, Outcome)>,
+{
+    const ATTEMPTS_PER_SIZE: u64 = 200;
+
+    // First look for the smallest size that still fails, so the reported seed stays replayable
+    let (seed, size) = (1..size)
+        .find_map(|smaller| {
+            (0..ATTEMPTS_PER_SIZE)
+                .map(|n| Rng::derive_seed(seed, n))
+                .find(|&s| check(&replay(generator, s, smaller)).is_some())
+                .map(|s| (s, smaller))
+        })
+        .unwrap_or((seed, size));
+
+    let generated = replay(generator, seed, size);
+
+    // Then drop lines one at a time for as long as the remainder keeps failing
+    let mut lines = generated.lines().collect::<Vec<_>>();
+    let mut i = 0;
+    while i < lines.len() && lines.len() > 1 {
+        let mut candidate = lines.clone();
+        candidate.remove(i);
+        if check(&candidate.join("\n")).is_some() {
+            lines = candidate;
+        }
+        else {
+            i += 1;
+        }
+    }
+
+    let minimized = lines.join("\n");
+    let (left, right) = check(&minimized).expect("minimized case should still fail");
+
+    Failure { seed, size, generated, minimized, left, right }
+}
@@ -0,0 +1,167 @@
+/// Something that can be turned into a `(dx, dy)` step on a grid, with `y` pointing down.
+pub trait Direction : Copy {
+    fn offset(self) -> (isize, isize);
+}
+
+/// The four orthogonal directions, in clockwise order starting from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir4 { Up, Right, Down, Left }
+
+impl Dir4 {
+    pub const fn all() -> [Dir4; 4] {
+        [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left]
+    }
+
+    pub fn rotate_cw(self) -> Dir4 {
+        Dir4::all()[(self as usize + 1) % 4]
+    }
+
+    pub fn rotate_ccw(self) -> Dir4 {
+        Dir4::all()[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Dir4 {
+        Dir4::all()[(self as usize + 2) % 4]
+    }
+}
+
+impl Direction for Dir4 {
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Dir4::Up => (0, -1),
+            Dir4::Right => (1, 0),
+            Dir4::Down => (0, 1),
+            Dir4::Left => (-1, 0),
+        }
+    }
+}
+
+/// The four orthogonal and four diagonal directions, in clockwise order starting from up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Dir8 { Up, UpRight, Right, DownRight, Down, DownLeft, Left, UpLeft }
+
+impl Dir8 {
+    pub const fn all() -> [Dir8; 8] {
+        [Dir8::Up, Dir8::UpRight, Dir8::Right, Dir8::DownRight, Dir8::Down, Dir8::DownLeft, Dir8::Left, Dir8::UpLeft]
+    }
+
+    /// Rotates by 45 degrees clockwise.
+    pub fn rotate_cw(self) -> Dir8 {
+        Dir8::all()[(self as usize + 1) % 8]
+    }
+
+    /// Rotates by 45 degrees counter-clockwise.
+    pub fn rotate_ccw(self) -> Dir8 {
+        Dir8::all()[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Dir8 {
+        Dir8::all()[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl Direction for Dir8 {
+    fn offset(self) -> (isize, isize) {
+        match self {
+            Dir8::Up => (0, -1),
+            Dir8::UpRight => (1, -1),
+            Dir8::Right => (1, 0),
+            Dir8::DownRight => (1, 1),
+            Dir8::Down => (0, 1),
+            Dir8::DownLeft => (-1, 1),
+            Dir8::Left => (-1, 0),
+            Dir8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        Dir8::all()[dir as usize * 2]
+    }
+}
+
+/// A position on a grid. Arithmetic that could leave the grid is checked, signed or wrapping
+/// rather than silently underflowing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Coord {
+    pub x: usize,
+    pub y: usize,
+}
+
+impl Coord {
+    pub const fn new(x: usize, y: usize) -> Coord {
+        Coord { x, y }
+    }
+
+    /// `None` if either component would go below 0 or overflow.
+    pub fn checked_add(self, (dx, dy): (isize, isize)) -> Option<Coord> {
+        Some(Coord {
+            x: self.x.checked_add_signed(dx)?,
+            y: self.y.checked_add_signed(dy)?,
+        })
+    }
+
+    /// Adds without any bounds, for positions that are allowed to leave the grid.
+    pub fn add_signed(self, (dx, dy): (isize, isize)) -> (isize, isize) {
+        (self.x as isize + dx, self.y as isize + dy)
+    }
+
+    /// Adds and wraps around a `width` by `height` torus.
+    pub fn wrapping_add(self, (dx, dy): (isize, isize), (width, height): (usize, usize)) -> Coord {
+        let (x, y) = self.add_signed((dx, dy));
+        Coord {
+            x: x.rem_euclid(width as isize) as usize,
+            y: y.rem_euclid(height as isize) as usize,
+        }
+    }
+
+    pub fn step(self, dir: impl Direction) -> Option<Coord> {
+        self.checked_add(dir.offset())
+    }
+
+    /// Converts a signed position back, `None` if either component is negative.
+    pub fn from_signed((x, y): (isize, isize)) -> Option<Coord> {
+        Some(Coord { x: usize::try_from(x).ok()?, y: usize::try_from(y).ok()? })
+    }
+}
+
+impl From<(usize, usize)> for Coord {
+    fn from((x, y): (usize, usize)) -> Self {
+        Coord { x, y }
+    }
+}
+
+impl From<Coord> for (usize, usize) {
+    fn from(coord: Coord) -> Self {
+        (coord.x, coord.y)
+    }
+}
+
+/// An axis-aligned rectangle of grid cells.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect {
+    pub x: usize,
+    pub y: usize,
+    pub width: usize,
+    pub height: usize,
+}
+
+impl Rect {
+    pub const fn new(x: usize, y: usize, width: usize, height: usize) -> Rect {
+        Rect { x, y, width, height }
+    }
+
+    pub fn contains(&self, coord: impl Into<Coord>) -> bool {
+        let Coord { x, y } = coord.into();
+        x >= self.x && y >= self.y && x - self.x < self.width && y - self.y < self.height
+    }
+
+    pub fn top_left(&self) -> Coord {
+        Coord::new(self.x, self.y)
+    }
+}
@@ -1,41 +1,84 @@
-use std::{slice::{Iter, Chunks}, ops::{IndexMut, Index, Deref}, fmt::Display, iter};
+use std::{slice::{Iter, IterMut}, iter::Enumerate, marker::PhantomData, ops::{IndexMut, Index, Deref, Range}, fmt::{Debug, Display}, iter, mem, vec};
 
 use itertools::Itertools;
 
-pub struct Grid<T> {
+use super::geom::{Coord, Rect};
+
+mod bits;
+mod fingerprint;
+mod image;
+mod layout;
+mod like;
+mod parse;
+mod prefix;
+mod regions;
+mod render;
+mod runs;
+mod sparse;
+mod symmetry;
+mod tiled;
+mod view;
+pub use aoc_macros::FromChar;
+pub use bits::BitGrid;
+pub use image::{take_recordings, FrameRecorder, Recording, Rgb};
+pub use layout::{ColumnMajor, Layout, RowMajor, Tiled};
+pub use like::GridLike;
+pub use parse::{FromChar, ParseGridError};
+pub use prefix::PrefixSums;
+pub use regions::{Components, Connectivity, Region};
+pub use render::GridRenderer;
+pub use runs::{Runs, Span};
+pub use sparse::SparseGrid;
+pub use symmetry::{Axis, ReflectionAxis, RotationalSymmetry};
+pub use tiled::TiledGrid;
+pub use view::{GridView, SubGrid, Transform, ViewLine};
+
+const OFFSETS4: [(isize, isize); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+const OFFSETS8: [(isize, isize); 8] = [(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// What neighbor iterators do with positions that fall off the grid.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum EdgePolicy {
+    #[default]
+    Skip,
+    /// Treat the grid as a torus
+    Wrap,
+}
+
+/// Ordered and hashed by cells in storage order, then by size. Storage order is reading order
+/// for the default [`RowMajor`] layout, but not for the others.
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Grid<T, L = RowMajor> {
     arr: Vec<T>,
     pub width: usize,
     pub height: usize,
+    layout: PhantomData<L>,
 }
 
-impl<T> Grid<T> {
-    pub fn new(width: usize, height: usize, init: Vec<T>) -> Grid<T> {
+impl<T, L : Layout> Grid<T, L> {
+    /// `init` holds the cells in reading order, whatever the layout.
+    pub fn new(width: usize, height: usize, init: Vec<T>) -> Grid<T, L> {
         if init.len() != width * height {
             panic!("Grid initialization size mismatch! {}x{} does not equal {}", width, height, init.len());
         }
-        Grid { arr: init, width, height }
-    }
-
-    pub fn new_from<F : Fn((usize, usize)) -> T>(width: usize, height: usize, init: F) -> Grid<T> {
-        let mut arr = Vec::with_capacity(width * height);
-        for y in 0..height {
-            for x in 0..width {
-                arr.push(init((x, y)))
-            }
-        }
-        Grid { arr, width, height }
+        Grid::<T> { arr: init, width, height, layout: PhantomData }.into_layout()
     }
 
-    pub fn with_indices(&self) -> GridIndices<T> {
-        GridIndices { iter: self.arr.iter(), width: self.width, offset: 0 }
+    pub fn new_from<F : Fn((usize, usize)) -> T>(width: usize, height: usize, init: F) -> Grid<T, L> {
+        let arr = (0..width * height)
+            .map(|i| init(L::position(i, width, height)))
+            .collect();
+        Grid { arr, width, height, layout: PhantomData }
     }
 
-    pub fn rows(&self) -> Chunks<'_, T> {
-        self.arr.chunks(self.width)
+    pub fn in_bounds(&self, coord: impl Into<Coord>) -> bool {
+        let Coord { x, y } = coord.into();
+        x < self.width && y < self.height
     }
 
-    pub fn get(&self, coord: (usize, usize)) -> Option<&T> {
-        if coord.0 <= self.width && coord.1 <= self.height {
+    pub fn get(&self, coord: impl Into<Coord>) -> Option<&T> {
+        let coord = coord.into();
+        if self.in_bounds(coord) {
             Some(&self[coord])
         }
         else {
@@ -43,8 +86,9 @@ impl<T> Grid<T> {
         }
     }
 
-    pub fn get_mut(&mut self, coord: (usize, usize)) -> Option<&mut T> {
-        if coord.0 < self.width && coord.1 < self.height {
+    pub fn get_mut(&mut self, coord: impl Into<Coord>) -> Option<&mut T> {
+        let coord = coord.into();
+        if self.in_bounds(coord) {
             Some(&mut self[coord])
         }
         else {
@@ -52,34 +96,395 @@ impl<T> Grid<T> {
         }
     }
 
-}
+    /// Every cell and its position, in reading order.
+    pub fn with_indices(&self) -> GridIndices<'_, T, L> {
+        GridIndices { grid: self, next: 0 }
+    }
 
-impl<T : Copy> Grid<T> {
-    pub fn map<F, U>(&self, f: F) -> Grid<U>
-        where F : Fn(T) -> U
+    /// A lazily transformed view of the grid.
+    pub fn view(&self, transform: Transform) -> GridView<'_, T, L> {
+        GridView::new(self, transform)
+    }
+
+    /// Every cell and its position, in storage order, which is only reading order for [`RowMajor`].
+    pub fn with_indices_mut(&mut self) -> GridIndicesMut<'_, T, L> {
+        GridIndicesMut { iter: self.arr.iter_mut().enumerate(), width: self.width, height: self.height, layout: PhantomData }
+    }
+
+    /// Every cell in storage order.
+    pub fn iter_mut(&mut self) -> IterMut<'_, T> {
+        self.arr.iter_mut()
+    }
+
+    pub fn rows(&self) -> Lines<'_, T, L> {
+        Lines { grid: self, horizontal: true, next: 0, end: self.height }
+    }
+
+    /// Panics if `y` is out of bounds.
+    pub fn row(&self, y: usize) -> Line<'_, T, L> {
+        if y >= self.height {
+            panic!("Row {} is outside the {}x{} grid", y, self.width, self.height);
+        }
+        Line::new(self, y, true, 0..self.width)
+    }
+
+    /// Panics if `y` is out of bounds.
+    pub fn row_mut(&mut self, y: usize) -> LineMut<'_, T, L> {
+        if y >= self.height {
+            panic!("Row {} is outside the {}x{} grid", y, self.width, self.height);
+        }
+        self.line_mut(y, true)
+    }
+
+    /// Every row at once, for writing. Use [`Grid::row_mut`] for just one.
+    pub fn rows_mut(&mut self) -> LinesMut<'_, T, L> {
+        self.lines_mut(true)
+    }
+
+    pub fn columns(&self) -> Lines<'_, T, L> {
+        Lines { grid: self, horizontal: false, next: 0, end: self.width }
+    }
+
+    /// Panics if `x` is out of bounds.
+    pub fn column(&self, x: usize) -> Line<'_, T, L> {
+        if x >= self.width {
+            panic!("Column {} is outside the {}x{} grid", x, self.width, self.height);
+        }
+        Line::new(self, x, false, 0..self.height)
+    }
+
+    /// Panics if `x` is out of bounds.
+    pub fn column_mut(&mut self, x: usize) -> LineMut<'_, T, L> {
+        if x >= self.width {
+            panic!("Column {} is outside the {}x{} grid", x, self.width, self.height);
+        }
+        self.line_mut(x, false)
+    }
+
+    /// Every column at once, for writing. Use [`Grid::column_mut`] for just one.
+    pub fn columns_mut(&mut self) -> LinesMut<'_, T, L> {
+        self.lines_mut(false)
+    }
+
+    /// Row `line`, or column `line` if not `horizontal`.
+    fn line_mut(&mut self, line: usize, horizontal: bool) -> LineMut<'_, T, L> {
+        let (width, height) = (self.width, self.height);
+        let len = if horizontal { width } else { height };
+        let mut cells = Vec::with_capacity(len);
+        // The layout keeps cells along a line in increasing storage order, so each can be split
+        // off the front of what's left
+        let (mut rest, mut offset) = (&mut self.arr[..], 0);
+        for i in 0..len {
+            let (x, y) = if horizontal { (i, line) } else { (line, i) };
+            let index = L::index(x, y, width, height);
+            let (cell, tail) = mem::take(&mut rest).split_at_mut(index - offset).1.split_first_mut().unwrap();
+            cells.push(cell);
+            (rest, offset) = (tail, index + 1);
+        }
+        LineMut::new(cells)
+    }
+
+    /// Every row, or every column if not `horizontal`.
+    fn lines_mut(&mut self, horizontal: bool) -> LinesMut<'_, T, L> {
+        let (width, height) = (self.width, self.height);
+        let (count, len) = if horizontal { (height, width) } else { (width, height) };
+        let mut lines = (0..count).map(|_| Vec::with_capacity(len)).collect_vec();
+        // Storage order reaches the cells of each line in order, so they need no sorting
+        for (i, cell) in self.arr.iter_mut().enumerate() {
+            let (x, y) = L::position(i, width, height);
+            lines[if horizontal { y } else { x }].push(cell);
+        }
+        LinesMut { lines: lines.into_iter(), layout: PhantomData }
+    }
+
+    /// A view of the `width` by `height` block whose top left corner is at `(x, y)`.
+    /// Panics if the block is not inside the grid.
+    pub fn subgrid(&self, x: usize, y: usize, width: usize, height: usize) -> SubGrid<'_, T, L> {
+        SubGrid::new(self, Rect::new(x, y, width, height))
+    }
+
+    /// The up to 4 orthogonal neighbors of `pos`, clockwise from up.
+    pub fn neighbors4(&self, pos: impl Into<Coord>) -> Neighbors<'_, T, L> {
+        self.neighbors_with(pos, &OFFSETS4)
+    }
+
+    /// The up to 8 orthogonal and diagonal neighbors of `pos`, clockwise from up.
+    pub fn neighbors8(&self, pos: impl Into<Coord>) -> Neighbors<'_, T, L> {
+        self.neighbors_with(pos, &OFFSETS8)
+    }
+
+    /// The cells at each of `offsets` from `pos`. Off-grid cells are skipped unless
+    /// [`Neighbors::with_edges`] says otherwise.
+    pub fn neighbors_with<'a>(&'a self, pos: impl Into<Coord>, offsets: &'a [(isize, isize)]) -> Neighbors<'a, T, L> {
+        Neighbors { grid: self, pos: pos.into(), offsets: offsets.iter(), edges: EdgePolicy::Skip }
+    }
+
+    /// Copies `other` into this grid with its top left corner at `at`. Anything that would land
+    /// outside this grid is dropped.
+    pub fn paste(&mut self, other: &Grid<T, L>, at: impl Into<Coord>)
+        where T : Clone
     {
-        Grid { arr: self.arr.iter().map(|r| f(*r)).collect_vec(), width: self.width, height: self.height }
+        let at = at.into();
+        let width = other.width.min(self.width.saturating_sub(at.x));
+        let height = other.height.min(self.height.saturating_sub(at.y));
+        for y in 0..height {
+            for x in 0..width {
+                self[(at.x + x, at.y + y)] = other[(x, y)].clone();
+            }
+        }
     }
 
-    pub fn grow(&self, n: usize, fill: T) -> Grid<T> {
-        let rows = self.arr.iter().chunks(self.width);
+    /// Every cell, in storage order, ready for [`Grid::rearrange`] to move out of.
+    fn take_cells(&mut self) -> Vec<Option<T>> {
+        mem::take(&mut self.arr).into_iter().map(Some).collect()
+    }
 
-        let expanded_rows = rows
-            .into_iter()
-            .flat_map(|c| c.chain(iter::repeat(&fill).take(n * 2)))
-            .map(|x| *x);
+    /// Refills the grid at a new size, moving the cell for each `(x, y)` out of `cells` at the
+    /// index `source(x, y)`.
+    fn rearrange(&mut self, width: usize, height: usize, mut cells: Vec<Option<T>>, source: impl Fn(usize, usize) -> usize) {
+        self.arr = (0..width * height)
+            .map(|i| {
+                let (x, y) = L::position(i, width, height);
+                cells[source(x, y)].take().expect("Each cell should be moved exactly once")
+            })
+            .collect();
+        self.width = width;
+        self.height = height;
+    }
 
-        let blank_top = iter::repeat(fill).take(self.width + n * 3);
-        let blank_bottom = iter::repeat(fill).take(self.width + n);
+    /// Inserts `row` so it becomes row `y`. Panics if `y` is past the bottom or `row` is the wrong
+    /// length, unless the grid has no rows yet, in which case `row` sets the width.
+    pub fn insert_row(&mut self, y: usize, row: impl IntoIterator<Item = T>) {
+        if y > self.height {
+            panic!("Cannot insert row {} into a grid with {} rows", y, self.height);
+        }
+        let (width, height) = (self.width, self.height);
+        let mut cells = self.take_cells();
+        let start = cells.len();
+        cells.extend(row.into_iter().map(Some));
+        let found = cells.len() - start;
+        if height > 0 && found != width {
+            panic!("Inserted row has {} cells but the grid is {} wide", found, width);
+        }
+
+        self.rearrange(found, height + 1, cells, |x, new_y| {
+            if new_y == y { start + x } else { L::index(x, new_y - (new_y > y) as usize, width, height) }
+        });
+    }
+
+    /// Inserts `col` so it becomes column `x`. Panics if `x` is past the right edge or `col` is the
+    /// wrong length, unless the grid has no columns yet, in which case `col` sets the height.
+    pub fn insert_col(&mut self, x: usize, col: impl IntoIterator<Item = T>) {
+        if x > self.width {
+            panic!("Cannot insert column {} into a grid with {} columns", x, self.width);
+        }
+        let (width, height) = (self.width, self.height);
+        let mut cells = self.take_cells();
+        let start = cells.len();
+        cells.extend(col.into_iter().map(Some));
+        let found = cells.len() - start;
+        if width > 0 && found != height {
+            panic!("Inserted column has {} cells but the grid is {} tall", found, height);
+        }
+
+        self.rearrange(width + 1, found, cells, |new_x, y| {
+            if new_x == x { start + y } else { L::index(new_x - (new_x > x) as usize, y, width, height) }
+        });
+    }
+
+    /// Removes row `y` and returns its cells. Panics if `y` is out of bounds.
+    pub fn remove_row(&mut self, y: usize) -> Vec<T> {
+        if y >= self.height {
+            panic!("Row {} is outside the {}x{} grid", y, self.width, self.height);
+        }
+        let (width, height) = (self.width, self.height);
+        let mut cells = self.take_cells();
+        let removed = (0..width)
+            .map(|x| cells[L::index(x, y, width, height)].take().unwrap())
+            .collect();
+
+        self.rearrange(width, height - 1, cells, |x, new_y| {
+            L::index(x, new_y + (new_y >= y) as usize, width, height)
+        });
+        removed
+    }
+
+    /// Removes column `x` and returns its cells. Panics if `x` is out of bounds.
+    pub fn remove_col(&mut self, x: usize) -> Vec<T> {
+        if x >= self.width {
+            panic!("Column {} is outside the {}x{} grid", x, self.width, self.height);
+        }
+        let (width, height) = (self.width, self.height);
+        let mut cells = self.take_cells();
+        let removed = (0..height)
+            .map(|y| cells[L::index(x, y, width, height)].take().unwrap())
+            .collect();
+
+        self.rearrange(width - 1, height, cells, |new_x, y| {
+            L::index(new_x + (new_x >= x) as usize, y, width, height)
+        });
+        removed
+    }
+
+    /// Repeats every row matching `predicate` so it appears `times` times. A `times` of 0 removes them.
+    pub fn duplicate_rows_where(&self, predicate: impl Fn(Line<T, L>) -> bool, times: usize) -> Grid<T, L>
+        where T : Clone
+    {
+        let sources = self.rows()
+            .enumerate()
+            .flat_map(|(y, row)| iter::repeat_n(y, if predicate(row) { times } else { 1 }))
+            .collect_vec();
+        Grid::new_from(self.width, sources.len(), |(x, y)| self[(x, sources[y])].clone())
+    }
+
+    /// Repeats every column matching `predicate` so it appears `times` times. A `times` of 0 removes them.
+    pub fn duplicate_cols_where(&self, predicate: impl Fn(Line<T, L>) -> bool, times: usize) -> Grid<T, L>
+        where T : Clone
+    {
+        let sources = self.columns()
+            .enumerate()
+            .flat_map(|(x, col)| iter::repeat_n(x, if predicate(col) { times } else { 1 }))
+            .collect_vec();
+        Grid::new_from(sources.len(), self.height, |(x, y)| self[(sources[x], y)].clone())
+    }
+
+    /// `other` placed to the right of this grid. Panics if the heights differ.
+    pub fn hstack(&self, other: &Grid<T, L>) -> Grid<T, L>
+        where T : Clone
+    {
+        if self.height != other.height {
+            panic!("Cannot place a grid {} tall beside one {} tall", other.height, self.height);
+        }
+        Grid::new_from(self.width + other.width, self.height, |(x, y)| {
+            if x < self.width { self[(x, y)].clone() } else { other[(x - self.width, y)].clone() }
+        })
+    }
+
+    /// `other` placed below this grid. Panics if the widths differ.
+    pub fn vstack(&self, other: &Grid<T, L>) -> Grid<T, L>
+        where T : Clone
+    {
+        if self.width != other.width {
+            panic!("Cannot place a grid {} wide below one {} wide", other.width, self.width);
+        }
+        Grid::new_from(self.width, self.height + other.height, |(x, y)| {
+            if y < self.height { self[(x, y)].clone() } else { other[(x, y - self.height)].clone() }
+        })
+    }
 
-        Grid {
-            arr: blank_top.chain(expanded_rows).chain(blank_bottom).collect_vec(),
-            width: self.width + n * 2,
-            height: self.height + n * 2,
+    /// Applies `transform` without allocating. Transforms that swap the axes need a square grid.
+    pub fn transform_in_place(&mut self, transform: Transform) {
+        if transform.swaps_axes() && self.width != self.height {
+            panic!("Cannot apply {:?} in place to a non-square {}x{} grid", transform, self.width, self.height);
         }
+
+        let (w, h) = (self.width, self.height);
+        let index = |x, y| L::index(x, y, w, h);
+        match transform {
+            Transform::Identity => {}
+            Transform::Rotate180 => {
+                // Swap each cell in the first half of reading order with its opposite in the second
+                for i in 0..w * h / 2 {
+                    let (x, y) = (i % w, i / w);
+                    self.arr.swap(index(x, y), index(w - 1 - x, h - 1 - y));
+                }
+            }
+            Transform::FlipHorizontal => {
+                for y in 0..h {
+                    for x in 0..w / 2 {
+                        self.arr.swap(index(x, y), index(w - 1 - x, y));
+                    }
+                }
+            }
+            Transform::FlipVertical => {
+                for y in 0..h / 2 {
+                    for x in 0..w {
+                        self.arr.swap(index(x, y), index(x, h - 1 - y));
+                    }
+                }
+            }
+            Transform::Transpose => {
+                for y in 0..h {
+                    for x in y + 1..w {
+                        self.arr.swap(index(x, y), index(y, x));
+                    }
+                }
+            }
+            Transform::AntiTranspose => {
+                for y in 0..h {
+                    for x in 0..w - 1 - y {
+                        self.arr.swap(index(x, y), index(w - 1 - y, h - 1 - x));
+                    }
+                }
+            }
+            Transform::RotateCw => {
+                self.transform_in_place(Transform::Transpose);
+                self.transform_in_place(Transform::FlipHorizontal);
+            }
+            Transform::RotateCcw => {
+                self.transform_in_place(Transform::Transpose);
+                self.transform_in_place(Transform::FlipVertical);
+            }
+        }
+    }
+
+    pub fn rotate_cw_in_place(&mut self) {
+        self.transform_in_place(Transform::RotateCw);
+    }
+
+    pub fn rotate_ccw_in_place(&mut self) {
+        self.transform_in_place(Transform::RotateCcw);
+    }
+
+    pub fn rotate_180_in_place(&mut self) {
+        self.transform_in_place(Transform::Rotate180);
+    }
+
+    pub fn flip_horizontal_in_place(&mut self) {
+        self.transform_in_place(Transform::FlipHorizontal);
+    }
+
+    pub fn flip_vertical_in_place(&mut self) {
+        self.transform_in_place(Transform::FlipVertical);
+    }
+
+    pub fn transpose_in_place(&mut self) {
+        self.transform_in_place(Transform::Transpose);
     }
 
-    pub fn subdivide_by<U : Copy, F, const W: usize, const H: usize>(&self, f: F) -> Grid<U>
+    pub fn anti_transpose_in_place(&mut self) {
+        self.transform_in_place(Transform::AntiTranspose);
+    }
+}
+
+impl<T : Copy, L : Layout> Grid<T, L> {
+    pub fn map<F, U>(&self, f: F) -> Grid<U, L>
+        where F : Fn(T) -> U
+    {
+        // Mapping cell by cell keeps every cell where it was, whatever the layout
+        Grid { arr: self.arr.iter().map(|r| f(*r)).collect_vec(), width: self.width, height: self.height, layout: PhantomData }
+    }
+
+    /// Surrounds the grid with `n` cells of `fill` on every side.
+    pub fn grow(&self, n: usize, fill: T) -> Grid<T, L> {
+        self.pad(n, n, n, n, fill)
+    }
+
+    /// Adds the given number of `fill` cells to each side of the grid.
+    pub fn pad(&self, top: usize, right: usize, bottom: usize, left: usize, fill: T) -> Grid<T, L> {
+        let xs = left..left + self.width;
+        let ys = top..top + self.height;
+        Grid::new_from(left + self.width + right, top + self.height + bottom, |(x, y)| {
+            if xs.contains(&x) && ys.contains(&y) { self[(x - left, y - top)] } else { fill }
+        })
+    }
+
+    /// Copies the cells inside `rect` into a new grid. Panics if `rect` is not inside the grid.
+    pub fn crop(&self, rect: Rect) -> Grid<T, L> {
+        self.subgrid(rect.x, rect.y, rect.width, rect.height).to_grid()
+    }
+
+    pub fn subdivide_by<U : Copy, F, const W: usize, const H: usize>(&self, f: F) -> Grid<U, L>
         where F : Fn(T) -> [[U; W]; H]
     {
         let grid = self.map(f);
@@ -88,40 +493,59 @@ impl<T : Copy> Grid<T> {
         })
     }
 
-    pub fn columns<'a>(&'a self) -> Columns<'a, T> {
-        Columns::new(self)
-    }
-
     pub fn flip_horizontal(&self) -> Self {
-        let arr = self.rows()
-            .flat_map(|r| r.iter().rev().map(|x| *x))
-            .collect_vec();
-        Grid { arr, ..*self }
+        self.transformed(Transform::FlipHorizontal)
     }
 
     pub fn transpose(&self) -> Self {
-        let arr = Vec::from_iter(
-            (0..self.width * self.height)
-                .map(|i| self[(i / self.height, i % self.height)])
-        );
-        Grid { arr, width: self.height, height: self.width }
+        self.transformed(Transform::Transpose)
+    }
+
+    pub fn transformed(&self, transform: Transform) -> Self {
+        self.view(transform).to_grid()
+    }
+
+    pub fn rotate_cw(&self) -> Self {
+        self.transformed(Transform::RotateCw)
+    }
+
+    pub fn rotate_ccw(&self) -> Self {
+        self.transformed(Transform::RotateCcw)
+    }
+
+    pub fn rotate_180(&self) -> Self {
+        self.transformed(Transform::Rotate180)
+    }
+
+    pub fn flip_vertical(&self) -> Self {
+        self.transformed(Transform::FlipVertical)
+    }
+
+    pub fn anti_transpose(&self) -> Self {
+        self.transformed(Transform::AntiTranspose)
+    }
+
+    /// The grid under each of the 8 symmetries of a rectangle, starting with the identity.
+    pub fn symmetries(&self) -> impl Iterator<Item = (Transform, Grid<T, L>)> + '_ {
+        Transform::all().into_iter().map(|t| (t, self.transformed(t)))
     }
 
 }
 
-impl From<&str> for Grid<char> {
+impl<L : Layout> From<&str> for Grid<char, L> {
     fn from(value: &str) -> Self {
         let width = value.find('\n').unwrap_or(value.len());
         let init: Vec<char> = value.chars()
             .filter(|c| *c != '\n')
             .collect();
-        let height = init.len() / width;
-        
+        let height = init.len().checked_div(width).unwrap_or(0);
+
         Grid::new(width, height, init)
     }
 }
 
-impl<T> Deref for Grid<T> {
+/// The cells in storage order, which is only reading order for [`RowMajor`].
+impl<T, L : Layout> Deref for Grid<T, L> {
     type Target = [T];
 
     fn deref(&self) -> &Self::Target {
@@ -129,122 +553,282 @@ impl<T> Deref for Grid<T> {
     }
 }
 
-impl<T> Index<(usize, usize)> for Grid<T> {
+impl<T, L : Layout> Index<(usize, usize)> for Grid<T, L> {
     type Output = T;
 
-    fn index(&self, index: (usize, usize)) -> &Self::Output {
-        &self.arr[index.0 + index.1 * self.width]
+    fn index(&self, (x, y): (usize, usize)) -> &Self::Output {
+        &self.arr[L::index(x, y, self.width, self.height)]
+    }
+}
+
+impl<T, L : Layout> IndexMut<(usize, usize)> for Grid<T, L> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut Self::Output {
+        &mut self.arr[L::index(x, y, self.width, self.height)]
     }
 }
 
-impl<T> IndexMut<(usize, usize)> for Grid<T> {
-    fn index_mut(&mut self, index: (usize, usize)) -> &mut Self::Output {
-        &mut self.arr[index.0 + index.1 * self.width]
+impl<T, L : Layout> Index<Coord> for Grid<T, L> {
+    type Output = T;
+
+    fn index(&self, index: Coord) -> &Self::Output {
+        &self[(index.x, index.y)]
     }
 }
 
-impl<T : Clone> Clone for Grid<T> {
-    fn clone(&self) -> Self {
-        Self { arr: self.arr.clone(), width: self.width, height: self.height }
+impl<T, L : Layout> IndexMut<Coord> for Grid<T, L> {
+    fn index_mut(&mut self, index: Coord) -> &mut Self::Output {
+        &mut self[(index.x, index.y)]
     }
 }
 
-impl<T : Display> Display for Grid<T> {
+impl<T : Debug, L : Layout> Debug for Grid<T, L> {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.arr.len() == 0 {
+        f.debug_struct("Grid")
+            .field("width", &self.width)
+            .field("height", &self.height)
+            .field("rows", &self.rows().map(|row| row.collect_vec()).collect_vec())
+            .finish()
+    }
+}
+
+impl<T : Display, L : Layout> Display for Grid<T, L> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.arr.is_empty() {
             return Ok(());
         }
 
-        fn write_row<T : Display>(f: &mut std::fmt::Formatter<'_>, row: &[T]) -> std::fmt::Result {
-            row[0].fmt(f)?;
-            for item in row.iter().skip(1) {
-                write!(f, " ")?;
-                item.fmt(f)?;
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
             }
-            Ok(())
-        }
-
-        write_row(f, &self.arr[0..self.width])?;
-        for row in self.arr.chunks(self.width).skip(1) {
-            write!(f, "\n")?;
-            write_row(f, row)?;
+            write!(f, "{}", row.format(" "))?;
         }
         Ok(())
     }
 }
 
-pub struct GridIndices<'a, T> {
-    iter: Iter<'a, T>,
-    width: usize,
-    offset: usize,
+pub struct GridIndices<'a, T, L = RowMajor> {
+    grid: &'a Grid<T, L>,
+    /// Position of the next cell in reading order
+    next: usize,
 }
 
-impl<'a, T> Iterator for GridIndices<'a, T> {
+impl<'a, T, L : Layout> Iterator for GridIndices<'a, T, L> {
     type Item = ((usize, usize), &'a T);
 
     fn next(&mut self) -> Option<Self::Item> {
-        match self.iter.next() {
-            None => None,
-            Some(next) => {
-                let x = self.offset % self.width;
-                let y = self.offset / self.width;
-                self.offset += 1;
-                Some(((x, y), next))
+        if self.next < self.grid.arr.len() {
+            let pos = (self.next % self.grid.width, self.next / self.grid.width);
+            self.next += 1;
+            Some((pos, &self.grid[pos]))
+        }
+        else {
+            None
+        }
+    }
+}
+
+pub struct Neighbors<'a, T, L = RowMajor> {
+    grid: &'a Grid<T, L>,
+    pos: Coord,
+    offsets: Iter<'a, (isize, isize)>,
+    edges: EdgePolicy,
+}
+
+impl<'a, T, L> Neighbors<'a, T, L> {
+    pub fn with_edges(self, edges: EdgePolicy) -> Neighbors<'a, T, L> {
+        Neighbors { edges, ..self }
+    }
+}
+
+impl<'a, T, L : Layout> Iterator for Neighbors<'a, T, L> {
+    type Item = (Coord, &'a T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        for &offset in self.offsets.by_ref() {
+            let neighbor = match self.edges {
+                EdgePolicy::Skip => self.pos.checked_add(offset).filter(|&n| self.grid.in_bounds(n)),
+                EdgePolicy::Wrap => Some(self.pos.wrapping_add(offset, (self.grid.width, self.grid.height))),
+            };
+            if let Some(neighbor) = neighbor {
+                return Some((neighbor, &self.grid[neighbor]));
             }
         }
-        
+        None
     }
 }
 
-pub struct Columns<'a, T> {
-    grid: &'a Grid<T>,
-    x: usize,
+pub struct GridIndicesMut<'a, T, L = RowMajor> {
+    iter: Enumerate<IterMut<'a, T>>,
+    width: usize,
+    height: usize,
+    layout: PhantomData<L>,
 }
 
-impl<'a, T> Columns<'a, T> {
-    fn new(grid: &'a Grid<T>) -> Columns<'a, T> {
-        Columns { grid, x: 0 }
+impl<'a, T, L : Layout> Iterator for GridIndicesMut<'a, T, L> {
+    type Item = ((usize, usize), &'a mut T);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (i, next) = self.iter.next()?;
+        Some((L::position(i, self.width, self.height), next))
     }
 }
 
-impl<'a, T : Sized> Iterator for Columns<'a, T> {
-    type Item = Column<'a, T>;
+/// Every row or every column of a grid, made by [`Grid::rows`] and [`Grid::columns`].
+pub struct Lines<'a, T, L = RowMajor> {
+    grid: &'a Grid<T, L>,
+    horizontal: bool,
+    next: usize,
+    end: usize,
+}
+
+impl<'a, T, L : Layout> Iterator for Lines<'a, T, L> {
+    type Item = Line<'a, T, L>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.x < self.grid.width {
-            let result = Column::new(&self.grid, self.x);
-            self.x += 1;
-            Some(result)
+        if self.next < self.end {
+            self.next += 1;
+            Some(self.line(self.next - 1))
         }
         else {
             None
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.next, Some(self.end - self.next))
+    }
 }
 
-pub struct Column<'a, T> {
-    grid: &'a Grid<T>,
-    x: usize,
-    y: usize,
+impl<'a, T, L : Layout> Lines<'a, T, L> {
+    fn line(&self, i: usize) -> Line<'a, T, L> {
+        let len = if self.horizontal { self.grid.width } else { self.grid.height };
+        Line::new(self.grid, i, self.horizontal, 0..len)
+    }
 }
 
-impl<'a, T> Column<'a, T> {
-    fn new(grid: &Grid<T>, x: usize) -> Column<T> {
-        Column { grid, x, y: 0 }
+impl<T, L : Layout> DoubleEndedIterator for Lines<'_, T, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.next < self.end {
+            self.end -= 1;
+            Some(self.line(self.end))
+        }
+        else {
+            None
+        }
     }
 }
 
-impl<'a, T : Sized> Iterator for Column<'a, T> {
+impl<T, L : Layout> ExactSizeIterator for Lines<'_, T, L> {}
+
+/// The cells of one row left to right, or one column top to bottom.
+pub struct Line<'a, T, L = RowMajor> {
+    grid: &'a Grid<T, L>,
+    /// `y` of a row or `x` of a column
+    line: usize,
+    horizontal: bool,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, T, L : Layout> Line<'a, T, L> {
+    /// The cells from `range.start` to `range.end` along row or column `line`.
+    fn new(grid: &'a Grid<T, L>, line: usize, horizontal: bool, range: Range<usize>) -> Line<'a, T, L> {
+        Line { grid, line, horizontal, start: range.start, end: range.end }
+    }
+
+    fn at(&self, i: usize) -> &'a T {
+        let pos = if self.horizontal { (i, self.line) } else { (self.line, i) };
+        &self.grid[pos]
+    }
+}
+
+impl<'a, T, L : Layout> Iterator for Line<'a, T, L> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.y < self.grid.height {
-            let result = &self.grid[(self.x, self.y)];
-            self.y += 1;
-            Some(result)
+        if self.start < self.end {
+            self.start += 1;
+            Some(self.at(self.start - 1))
+        }
+        else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.start, Some(self.end - self.start))
+    }
+}
+
+impl<T, L : Layout> DoubleEndedIterator for Line<'_, T, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.end -= 1;
+            Some(self.at(self.end))
         }
         else {
             None
         }
     }
 }
+
+impl<T, L : Layout> ExactSizeIterator for Line<'_, T, L> {}
+
+/// Every row or every column of a grid for writing, made by [`Grid::rows_mut`] and [`Grid::columns_mut`].
+pub struct LinesMut<'a, T, L = RowMajor> {
+    lines: vec::IntoIter<Vec<&'a mut T>>,
+    layout: PhantomData<L>,
+}
+
+impl<'a, T, L : Layout> Iterator for LinesMut<'a, T, L> {
+    type Item = LineMut<'a, T, L>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.lines.next().map(LineMut::new)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.lines.size_hint()
+    }
+}
+
+impl<T, L : Layout> DoubleEndedIterator for LinesMut<'_, T, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.lines.next_back().map(LineMut::new)
+    }
+}
+
+impl<T, L : Layout> ExactSizeIterator for LinesMut<'_, T, L> {}
+
+/// Mutable cells of one row left to right, or one column top to bottom.
+pub struct LineMut<'a, T, L = RowMajor> {
+    cells: vec::IntoIter<&'a mut T>,
+    layout: PhantomData<L>,
+}
+
+impl<'a, T, L : Layout> LineMut<'a, T, L> {
+    fn new(cells: Vec<&'a mut T>) -> LineMut<'a, T, L> {
+        LineMut { cells: cells.into_iter(), layout: PhantomData }
+    }
+}
+
+impl<'a, T, L : Layout> Iterator for LineMut<'a, T, L> {
+    type Item = &'a mut T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.cells.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.cells.size_hint()
+    }
+}
+
+impl<T, L : Layout> DoubleEndedIterator for LineMut<'_, T, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.cells.next_back()
+    }
+}
+
+impl<T, L : Layout> ExactSizeIterator for LineMut<'_, T, L> {}
@@ -0,0 +1,288 @@
+use std::{fmt::Display, ops::{BitAnd, BitOr, BitXor, Index, Not}};
+
+use crate::utils::geom::Coord;
+
+use super::{Grid, GridLike, Layout, OFFSETS4, OFFSETS8};
+
+const WORD_BITS: usize = u64::BITS as usize;
+
+/// A grid of booleans packed into `u64` words. Each row starts on a fresh word with cell `x` at bit
+/// `x % 64` of word `x / 64`, so whole rows can be compared, counted and combined a word at a time.
+/// Bits past the right edge are always zero, which keeps equality and hashing exact.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct BitGrid {
+    words: Vec<u64>,
+    words_per_row: usize,
+    width: usize,
+    height: usize,
+}
+
+/// Writes `src` moved `dx` cells to the right into `dst`, filling with zeros.
+fn shift_words(src: &[u64], dst: &mut [u64], dx: isize) {
+    let (words, bits) = (dx.unsigned_abs() / WORD_BITS, dx.unsigned_abs() % WORD_BITS);
+    for (i, out) in dst.iter_mut().enumerate() {
+        *out = if dx >= 0 {
+            let word = i.checked_sub(words).map_or(0, |j| src[j]);
+            let carry = i.checked_sub(words + 1).map_or(0, |j| src[j]);
+            if bits == 0 { word } else { (word << bits) | (carry >> (WORD_BITS - bits)) }
+        }
+        else {
+            let word = src.get(i + words).copied().unwrap_or(0);
+            let carry = src.get(i + words + 1).copied().unwrap_or(0);
+            if bits == 0 { word } else { (word >> bits) | (carry << (WORD_BITS - bits)) }
+        };
+    }
+}
+
+impl BitGrid {
+    /// An all-false grid.
+    pub fn new(width: usize, height: usize) -> BitGrid {
+        let words_per_row = width.div_ceil(WORD_BITS);
+        BitGrid { words: vec![0; words_per_row * height], words_per_row, width, height }
+    }
+
+    /// Sets the cells of `grid` that match `predicate`.
+    pub fn from_grid<T, L : Layout>(grid: &Grid<T, L>, predicate: impl Fn(&T) -> bool) -> BitGrid {
+        let mut bits = BitGrid::new(grid.width, grid.height);
+        for ((x, y), item) in grid.with_indices() {
+            if predicate(item) {
+                bits.set((x, y), true);
+            }
+        }
+        bits
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, coord: impl Into<Coord>) -> bool {
+        let Coord { x, y } = coord.into();
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, coord: impl Into<Coord>) -> Option<bool> {
+        let coord = coord.into();
+        if self.in_bounds(coord) {
+            Some(self.row(coord.y)[coord.x / WORD_BITS] >> (coord.x % WORD_BITS) & 1 == 1)
+        }
+        else {
+            None
+        }
+    }
+
+    /// Panics if `coord` is outside the grid.
+    pub fn set(&mut self, coord: impl Into<Coord>, value: bool) {
+        let Coord { x, y } = coord.into();
+        if !self.in_bounds((x, y)) {
+            panic!("({}, {}) is outside the {}x{} grid", x, y, self.width, self.height);
+        }
+        let word = &mut self.words[y * self.words_per_row + x / WORD_BITS];
+        let mask = 1 << (x % WORD_BITS);
+        if value {
+            *word |= mask;
+        }
+        else {
+            *word &= !mask;
+        }
+    }
+
+    /// The packed words of row `y`.
+    pub fn row(&self, y: usize) -> &[u64] {
+        &self.words[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    /// Number of set cells.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Number of set cells in row `y`.
+    pub fn row_count_ones(&self, y: usize) -> usize {
+        self.row(y).iter().map(|w| w.count_ones() as usize).sum()
+    }
+
+    /// Number of cells that differ between rows `y1` and `y2`.
+    pub fn row_diff(&self, y1: usize, y2: usize) -> usize {
+        self.row(y1).iter()
+            .zip(self.row(y2))
+            .map(|(a, b)| (a ^ b).count_ones() as usize)
+            .sum()
+    }
+
+    /// Positions of the set cells, in reading order.
+    pub fn iter_ones(&self) -> impl Iterator<Item = Coord> + '_ {
+        (0..self.height).flat_map(move |y| {
+            self.row(y).iter().enumerate().flat_map(move |(i, &word)| {
+                let mut word = word;
+                std::iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    Some(Coord::new(i * WORD_BITS + bit, y))
+                })
+            })
+        })
+    }
+
+    /// The grid with its rows and columns swapped, so column operations can be done as row operations.
+    pub fn transpose(&self) -> BitGrid {
+        let mut transposed = BitGrid::new(self.height, self.width);
+        for Coord { x, y } in self.iter_ones() {
+            transposed.set((y, x), true);
+        }
+        transposed
+    }
+
+    /// Every cell moved by `(dx, dy)`. Cells moved off the grid are lost and the gap is filled with false.
+    pub fn shift(&self, (dx, dy): (isize, isize)) -> BitGrid {
+        let mut shifted = BitGrid::new(self.width, self.height);
+        for y in 0..self.height {
+            let Some(src_y) = y.checked_add_signed(-dy).filter(|&src_y| src_y < self.height) else {
+                continue;
+            };
+            let start = y * self.words_per_row;
+            shift_words(self.row(src_y), &mut shifted.words[start..start + self.words_per_row], dx);
+        }
+        shifted.clear_padding();
+        shifted
+    }
+
+    /// Sets every cell next to a set cell in one of `offsets`, keeping the cells already set.
+    pub fn dilate_with(&self, offsets: &[(isize, isize)]) -> BitGrid {
+        offsets.iter().fold(self.clone(), |grid, &offset| &grid | &self.shift(offset))
+    }
+
+    /// Clears every cell that has an unset neighbor in one of `offsets`. Cells off the grid count as unset.
+    pub fn erode_with(&self, offsets: &[(isize, isize)]) -> BitGrid {
+        offsets.iter().fold(self.clone(), |grid, &(dx, dy)| &grid & &self.shift((-dx, -dy)))
+    }
+
+    pub fn dilate4(&self) -> BitGrid {
+        self.dilate_with(&OFFSETS4)
+    }
+
+    pub fn dilate8(&self) -> BitGrid {
+        self.dilate_with(&OFFSETS8)
+    }
+
+    pub fn erode4(&self) -> BitGrid {
+        self.erode_with(&OFFSETS4)
+    }
+
+    pub fn erode8(&self) -> BitGrid {
+        self.erode_with(&OFFSETS8)
+    }
+
+    pub fn to_grid(&self) -> Grid<bool> {
+        Grid::new_from(self.width, self.height, |pos| self[pos])
+    }
+
+    fn clear_padding(&mut self) {
+        let used = self.width % WORD_BITS;
+        if used == 0 {
+            return;
+        }
+        let mask = (1 << used) - 1;
+        for row in self.words.chunks_mut(self.words_per_row) {
+            *row.last_mut().unwrap() &= mask;
+        }
+    }
+
+    fn zip_words(&self, other: &BitGrid, f: impl Fn(u64, u64) -> u64) -> BitGrid {
+        if (self.width, self.height) != (other.width, other.height) {
+            panic!("Cannot combine a {}x{} grid with a {}x{} grid", self.width, self.height, other.width, other.height);
+        }
+        let words = self.words.iter().zip(&other.words).map(|(&a, &b)| f(a, b)).collect();
+        BitGrid { words, ..*self }
+    }
+}
+
+impl BitAnd for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitand(self, rhs: Self) -> BitGrid {
+        self.zip_words(rhs, |a, b| a & b)
+    }
+}
+
+impl BitOr for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitor(self, rhs: Self) -> BitGrid {
+        self.zip_words(rhs, |a, b| a | b)
+    }
+}
+
+impl BitXor for &BitGrid {
+    type Output = BitGrid;
+
+    fn bitxor(self, rhs: Self) -> BitGrid {
+        self.zip_words(rhs, |a, b| a ^ b)
+    }
+}
+
+impl Not for &BitGrid {
+    type Output = BitGrid;
+
+    fn not(self) -> BitGrid {
+        let mut inverted = BitGrid { words: self.words.iter().map(|w| !w).collect(), ..*self };
+        inverted.clear_padding();
+        inverted
+    }
+}
+
+impl<C : Into<Coord>> Index<C> for BitGrid {
+    type Output = bool;
+
+    fn index(&self, index: C) -> &bool {
+        let Coord { x, y } = index.into();
+        match self.get((x, y)) {
+            Some(true) => &true,
+            Some(false) => &false,
+            None => panic!("({}, {}) is outside the {}x{} grid", x, y, self.width, self.height),
+        }
+    }
+}
+
+impl GridLike for BitGrid {
+    type Item = bool;
+    type Pos = Coord;
+
+    fn cell(&self, pos: Coord) -> Option<&bool> {
+        self.get(pos).map(|bit| if bit { &true } else { &false })
+    }
+
+    fn offset(&self, pos: Coord, offset: (isize, isize)) -> Option<Coord> {
+        pos.checked_add(offset).filter(|&n| self.in_bounds(n))
+    }
+
+    fn extent(&self) -> (Coord, usize, usize) {
+        (Coord::new(0, 0), self.width, self.height)
+    }
+
+    fn signed(pos: Coord) -> (isize, isize) {
+        (pos.x as isize, pos.y as isize)
+    }
+}
+
+/// Set cells as `#` and unset cells as `.`, like puzzle input.
+impl Display for BitGrid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for y in 0..self.height {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for x in 0..self.width {
+                write!(f, "{}", if self[(x, y)] { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,61 @@
+use std::hash::{Hash, Hasher};
+
+use crate::utils::geom::Coord;
+
+use super::{Grid, Layout};
+
+/// The splitmix64 finalizer, spreads every input bit across the output.
+fn mix(mut x: u64) -> u64 {
+    x = (x ^ (x >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+    x = (x ^ (x >> 27)).wrapping_mul(0x94d049bb133111eb);
+    x ^ (x >> 31)
+}
+
+/// Multiply-rotate hasher, far cheaper per cell than the default SipHash.
+#[derive(Default)]
+struct CellHasher(u64);
+
+impl Hasher for CellHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for &b in bytes {
+            self.write_u64(b as u64);
+        }
+    }
+
+    fn write_u64(&mut self, i: u64) {
+        self.0 = (self.0.rotate_left(5) ^ i).wrapping_mul(0x517cc1b727220a95);
+    }
+
+    fn write_usize(&mut self, i: usize) {
+        self.write_u64(i as u64);
+    }
+}
+
+fn cell_fingerprint<T : Hash>(index: usize, item: &T) -> u64 {
+    let mut hasher = CellHasher::default();
+    item.hash(&mut hasher);
+    mix(hasher.finish() ^ mix(index as u64))
+}
+
+impl<T : Hash, L : Layout> Grid<T, L> {
+    /// A 64-bit hash of the grid's size and cells. Equal grids always have equal fingerprints,
+    /// but different grids can collide, so compare the grids themselves before trusting a match.
+    /// Fingerprints of grids with different layouts can't be compared.
+    pub fn fingerprint(&self) -> u64 {
+        self.arr.iter()
+            .enumerate()
+            .fold(mix((self.width as u64) << 32 | self.height as u64), |fp, (i, item)| fp ^ cell_fingerprint(i, item))
+    }
+
+    /// Updates a `fingerprint` of this grid after the cell at `pos` changed from `old` to its current
+    /// value, without rehashing every cell.
+    pub fn update_fingerprint(&self, fingerprint: u64, pos: impl Into<Coord>, old: &T) -> u64 {
+        let Coord { x, y } = pos.into();
+        let i = L::index(x, y, self.width, self.height);
+        fingerprint ^ cell_fingerprint(i, old) ^ cell_fingerprint(i, &self.arr[i])
+    }
+}
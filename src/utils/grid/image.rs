@@ -0,0 +1,204 @@
+use std::{fs::{self, File}, io::{self, BufWriter, Write}, mem, path::{Path, PathBuf}, sync::Mutex};
+
+use crate::RECORD;
+
+use super::{Grid, Layout};
+
+pub type Rgb = [u8; 3];
+
+fn create(path: &Path) -> io::Result<BufWriter<File>> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    Ok(BufWriter::new(File::create(path)?))
+}
+
+fn write_ppm(path: &Path, width: usize, height: usize, pixels: &[Rgb]) -> io::Result<()> {
+    let mut out = create(path)?;
+    write!(out, "P6\n{} {}\n255\n", width, height)?;
+    out.write_all(pixels.as_flattened())?;
+    out.flush()
+}
+
+#[cfg(feature = "images")]
+fn write_png(path: &Path, width: usize, height: usize, pixels: &[Rgb]) -> io::Result<()> {
+    let mut encoder = png::Encoder::new(create(path)?, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    encoder.write_header()?.write_image_data(pixels.as_flattened())?;
+    Ok(())
+}
+
+#[cfg(not(feature = "images"))]
+fn write_png(_: &Path, _: usize, _: usize, _: &[Rgb]) -> io::Result<()> {
+    Err(io::Error::new(io::ErrorKind::Unsupported, "PNG output needs the `images` feature"))
+}
+
+impl<T, L : Layout> Grid<T, L> {
+    /// Writes one pixel per cell, colored by `palette`. Saves a PNG if `path` ends in `.png`
+    /// (with the `images` feature), otherwise a binary PPM.
+    pub fn write_image(&self, path: impl AsRef<Path>, palette: impl Fn(&T) -> Rgb) -> io::Result<()> {
+        let path = path.as_ref();
+        let pixels = self.with_indices().map(|(_, item)| palette(item)).collect::<Vec<_>>();
+        match path.extension().and_then(|e| e.to_str()) {
+            Some("png") => write_png(path, self.width, self.height, &pixels),
+            _ => write_ppm(path, self.width, self.height, &pixels),
+        }
+    }
+
+    /// Writes one gray pixel per cell to a binary PGM, with brightness given by `palette`.
+    pub fn write_gray_image(&self, path: impl AsRef<Path>, palette: impl Fn(&T) -> u8) -> io::Result<()> {
+        let mut out = create(path.as_ref())?;
+        write!(out, "P5\n{} {}\n255\n", self.width, self.height)?;
+        out.write_all(&self.with_indices().map(|(_, item)| palette(item)).collect::<Vec<_>>())?;
+        out.flush()
+    }
+}
+
+struct Frame {
+    width: usize,
+    height: usize,
+    pixels: Vec<Rgb>,
+}
+
+/// Recordings finished while solving, waiting for the runner to save them
+static FINISHED: Mutex<Vec<Recording>> = Mutex::new(Vec::new());
+
+/// The frames of a finished [`FrameRecorder`], along with where they should be saved.
+pub struct Recording {
+    path: PathBuf,
+    frames: Vec<Frame>,
+}
+
+/// Every recording finished since the last call, oldest first.
+pub fn take_recordings() -> Vec<Recording> {
+    mem::take(&mut *FINISHED.lock().unwrap())
+}
+
+/// Collects grids as they change over the course of a part and saves them as an animation.
+///
+/// Made with [`FrameRecorder::if_recording`], a recorder does nothing unless the day was run with
+/// `record`, so parts can push frames unconditionally.
+pub struct FrameRecorder<'a, T> {
+    palette: Box<dyn Fn(&T) -> Rgb + 'a>,
+    frames: Vec<Frame>,
+    scale: usize,
+    enabled: bool,
+}
+
+impl<'a, T> FrameRecorder<'a, T> {
+    pub fn new(palette: impl Fn(&T) -> Rgb + 'a) -> Self {
+        FrameRecorder { palette: Box::new(palette), frames: Vec::new(), scale: 1, enabled: true }
+    }
+
+    /// A recorder that only keeps frames while [`RECORD`] is set.
+    pub fn if_recording(palette: impl Fn(&T) -> Rgb + 'a) -> Self {
+        let enabled = RECORD.get(|record| record == Some(&true));
+        FrameRecorder { enabled, ..FrameRecorder::new(palette) }
+    }
+
+    /// Draws each cell as a `scale` by `scale` block of pixels.
+    pub fn with_scale(self, scale: usize) -> Self {
+        FrameRecorder { scale: scale.max(1), ..self }
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.enabled
+    }
+
+    pub fn len(&self) -> usize {
+        self.frames.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.frames.is_empty()
+    }
+
+    pub fn push<L : Layout>(&mut self, grid: &Grid<T, L>) {
+        if !self.enabled {
+            return;
+        }
+
+        let scale = self.scale;
+        let mut pixels = Vec::with_capacity(grid.len() * scale * scale);
+        for row in grid.rows() {
+            let row_pixels = row
+                .flat_map(|item| std::iter::repeat_n((self.palette)(item), scale))
+                .collect::<Vec<_>>();
+            for _ in 0..scale {
+                pixels.extend_from_slice(&row_pixels);
+            }
+        }
+
+        self.frames.push(Frame { width: grid.width * scale, height: grid.height * scale, pixels });
+    }
+
+    /// Hands the frames over to be saved at `path` once the part returns, so a failed write is
+    /// reported by the runner instead of failing the part. Does nothing if no frames were kept.
+    pub fn finish(self, path: impl AsRef<Path>) {
+        if self.enabled && !self.frames.is_empty() {
+            FINISHED.lock().unwrap().push(Recording { path: path.as_ref().to_path_buf(), frames: self.frames });
+        }
+    }
+}
+
+impl Recording {
+    /// Saves the frames as `{path}.gif` with the `images` feature, otherwise as a numbered sequence
+    /// of PPMs `{path}-0000.ppm`, `{path}-0001.ppm`, and so on, replacing any frames already there.
+    /// Returns the first file written.
+    pub fn save(&self) -> io::Result<PathBuf> {
+        self.save_frames(&self.path)
+    }
+
+    #[cfg(feature = "images")]
+    fn save_frames(&self, path: &Path) -> io::Result<PathBuf> {
+        fn dimension(pixels: usize) -> io::Result<u16> {
+            u16::try_from(pixels).map_err(|_| io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("A frame {} pixels across is too large for a GIF, which allows at most {}", pixels, u16::MAX),
+            ))
+        }
+
+        let path = path.with_extension("gif");
+        let Frame { width, height, .. } = self.frames[0];
+        let mut encoder = gif::Encoder::new(create(&path)?, dimension(width)?, dimension(height)?, &[])
+            .map_err(io::Error::other)?;
+        encoder.set_repeat(gif::Repeat::Infinite).map_err(io::Error::other)?;
+
+        for frame in &self.frames {
+            let rgb = frame.pixels.as_flattened();
+            let mut gif_frame = gif::Frame::from_rgb_speed(dimension(frame.width)?, dimension(frame.height)?, rgb, 10);
+            gif_frame.delay = 5;
+            encoder.write_frame(&gif_frame).map_err(io::Error::other)?;
+        }
+
+        Ok(path)
+    }
+
+    #[cfg(not(feature = "images"))]
+    fn save_frames(&self, path: &Path) -> io::Result<PathBuf> {
+        let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("frame");
+        let frame_path = |i: usize| path.with_file_name(format!("{}-{:04}.ppm", name, i));
+
+        // Remove the frames of an earlier recording, which may have run longer than this one
+        let dir = path.parent().filter(|dir| !dir.as_os_str().is_empty()).unwrap_or(Path::new("."));
+        if dir.is_dir() {
+            for entry in fs::read_dir(dir)? {
+                let entry = entry?;
+                let is_old_frame = entry.file_name()
+                    .to_str()
+                    .and_then(|file| file.strip_prefix(name)?.strip_prefix('-')?.strip_suffix(".ppm"))
+                    .is_some_and(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()));
+                if is_old_frame {
+                    fs::remove_file(entry.path())?;
+                }
+            }
+        }
+
+        for (i, frame) in self.frames.iter().enumerate() {
+            write_ppm(&frame_path(i), frame.width, frame.height, &frame.pixels)?;
+        }
+
+        Ok(frame_path(0))
+    }
+}
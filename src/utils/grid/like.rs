@@ -0,0 +1,72 @@
+use std::hash::Hash;
+
+use crate::utils::geom::Coord;
+
+use super::{Grid, Layout, OFFSETS4, OFFSETS8};
+
+/// What [`Grid`] and [`SparseGrid`](super::SparseGrid) have in common, so neighbor iteration,
+/// rendering and searches can be written once for both.
+pub trait GridLike {
+    type Item;
+    /// How cells are addressed, [`Coord`] for a [`Grid`] and signed `(x, y)` for a sparse grid
+    type Pos : Copy + Eq + Hash;
+
+    /// The cell at `pos`, `None` if `pos` is outside the grid.
+    fn cell(&self, pos: Self::Pos) -> Option<&Self::Item>;
+
+    /// The position `offset` away from `pos`, `None` if that is outside the grid.
+    fn offset(&self, pos: Self::Pos, offset: (isize, isize)) -> Option<Self::Pos>;
+
+    /// The top left corner, width and height of the smallest rectangle holding every cell.
+    fn extent(&self) -> (Self::Pos, usize, usize);
+
+    /// `pos` as signed `(x, y)`, used to label rows and columns.
+    fn signed(pos: Self::Pos) -> (isize, isize);
+
+    /// The cells at each of `offsets` from `pos`, skipping any outside the grid.
+    fn neighbors_with<'a>(&'a self, pos: Self::Pos, offsets: &'a [(isize, isize)]) -> impl Iterator<Item = (Self::Pos, &'a Self::Item)> + 'a {
+        offsets.iter().filter_map(move |&offset| {
+            let neighbor = self.offset(pos, offset)?;
+            Some((neighbor, self.cell(neighbor)?))
+        })
+    }
+
+    /// The orthogonal neighbors of `pos`, clockwise from up.
+    fn neighbors4(&self, pos: Self::Pos) -> impl Iterator<Item = (Self::Pos, &Self::Item)> {
+        self.neighbors_with(pos, &OFFSETS4)
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos`, clockwise from up.
+    fn neighbors8(&self, pos: Self::Pos) -> impl Iterator<Item = (Self::Pos, &Self::Item)> {
+        self.neighbors_with(pos, &OFFSETS8)
+    }
+
+    /// Every position in [`GridLike::extent`], in reading order.
+    fn positions(&self) -> impl Iterator<Item = Self::Pos> + '_ {
+        let (origin, width, height) = self.extent();
+        (0..height as isize)
+            .flat_map(move |y| (0..width as isize).map(move |x| (x, y)))
+            .filter_map(move |offset| self.offset(origin, offset))
+    }
+}
+
+impl<T, L : Layout> GridLike for Grid<T, L> {
+    type Item = T;
+    type Pos = Coord;
+
+    fn cell(&self, pos: Coord) -> Option<&T> {
+        self.get(pos)
+    }
+
+    fn offset(&self, pos: Coord, offset: (isize, isize)) -> Option<Coord> {
+        pos.checked_add(offset).filter(|&n| self.in_bounds(n))
+    }
+
+    fn extent(&self) -> (Coord, usize, usize) {
+        (Coord::new(0, 0), self.width, self.height)
+    }
+
+    fn signed(pos: Coord) -> (isize, isize) {
+        (pos.x as isize, pos.y as isize)
+    }
+}
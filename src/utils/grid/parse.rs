@@ -0,0 +1,62 @@
+use std::{error::Error, fmt::Display};
+
+use super::{Grid, Layout};
+
+/// A type that a single character of puzzle input can be parsed into. Can be derived for enums.
+pub trait FromChar : Sized {
+    fn from_char(c: char) -> Option<Self>;
+}
+
+impl FromChar for char {
+    fn from_char(c: char) -> Option<Self> {
+        Some(c)
+    }
+}
+
+/// Why [`Grid::parse`] rejected its input. Rows and columns are 0-indexed, like grid coordinates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseGridError {
+    UnknownChar { row: usize, col: usize, c: char },
+    /// A row was a different length than the first row
+    RaggedRow { row: usize, expected: usize, found: usize },
+}
+
+impl Display for ParseGridError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseGridError::UnknownChar { row, col, c } => {
+                write!(f, "unknown character {:?} at row {}, column {}", c, row, col)
+            }
+            ParseGridError::RaggedRow { row, expected, found } => {
+                write!(f, "row {} has {} cells but the first row has {}", row, found, expected)
+            }
+        }
+    }
+}
+
+impl Error for ParseGridError {}
+
+impl<T : FromChar, L : Layout> Grid<T, L> {
+    /// Parses one cell per character, one row per line.
+    pub fn parse(input: &str) -> Result<Grid<T, L>, ParseGridError> {
+        let mut arr = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let mut found = 0;
+            for (col, c) in line.chars().enumerate() {
+                arr.push(T::from_char(c).ok_or(ParseGridError::UnknownChar { row, col, c })?);
+                found += 1;
+            }
+
+            let expected = *width.get_or_insert(found);
+            if found != expected {
+                return Err(ParseGridError::RaggedRow { row, expected, found });
+            }
+            height += 1;
+        }
+
+        Ok(Grid::new(width.unwrap_or(0), height, arr))
+    }
+}
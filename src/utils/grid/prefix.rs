@@ -0,0 +1,78 @@
+use std::ops::{Add, Sub};
+
+use crate::utils::geom::Rect;
+
+use super::{Grid, Layout, Line};
+
+/// A summed-area table made by [`Grid::prefix_sums`], answering rectangle sums in constant time.
+#[derive(Debug, Clone)]
+pub struct PrefixSums<N> {
+    /// Entry `(x, y)` is the sum of every cell above and to the left of `(x, y)`, exclusive,
+    /// so the table is one wider and taller than the grid
+    table: Grid<N>,
+}
+
+impl<N : Copy + Add<Output = N> + Sub<Output = N>> PrefixSums<N> {
+    pub fn width(&self) -> usize {
+        self.table.width - 1
+    }
+
+    pub fn height(&self) -> usize {
+        self.table.height - 1
+    }
+
+    /// Sum of the cells inside `rect`. Panics if `rect` is not inside the grid.
+    pub fn sum(&self, rect: Rect) -> N {
+        let (x1, y1, x2, y2) = (rect.x, rect.y, rect.x + rect.width, rect.y + rect.height);
+        if x2 > self.width() || y2 > self.height() {
+            panic!("{:?} is outside the {}x{} grid", rect, self.width(), self.height());
+        }
+        // Added before subtracting so unsigned sums can't underflow
+        self.table[(x2, y2)] + self.table[(x1, y1)] - self.table[(x1, y2)] - self.table[(x2, y1)]
+    }
+
+    /// Sum of every cell.
+    pub fn total(&self) -> N {
+        self.table[(self.width(), self.height())]
+    }
+}
+
+/// Running totals of `values`, starting from zero, so `sums[b] - sums[a]` is the sum of `values[a..b]`.
+fn running_sums<N : Copy + Add<Output = N> + Default>(values: impl Iterator<Item = N>) -> Vec<N> {
+    let mut sums = vec![N::default()];
+    for value in values {
+        sums.push(*sums.last().unwrap() + value);
+    }
+    sums
+}
+
+impl<T, L : Layout> Grid<T, L> {
+    /// A summed-area table of `f` applied to each cell.
+    pub fn prefix_sums<N, F>(&self, f: F) -> PrefixSums<N>
+        where N : Copy + Add<Output = N> + Sub<Output = N> + Default, F : Fn(&T) -> N
+    {
+        let mut table = Grid::new_from(self.width + 1, self.height + 1, |_| N::default());
+        for y in 0..self.height {
+            for x in 0..self.width {
+                table[(x + 1, y + 1)] = f(&self[(x, y)]) + table[(x, y + 1)] + table[(x + 1, y)] - table[(x, y)];
+            }
+        }
+        PrefixSums { table }
+    }
+
+    /// Running totals of `f` over the rows: entry `y` is the sum for every row above `y`, and there
+    /// is one more entry than there are rows.
+    pub fn row_prefix_sums<N, F>(&self, f: F) -> Vec<N>
+        where N : Copy + Add<Output = N> + Default, F : Fn(Line<T, L>) -> N
+    {
+        running_sums(self.rows().map(f))
+    }
+
+    /// Running totals of `f` over the columns: entry `x` is the sum for every column left of `x`,
+    /// and there is one more entry than there are columns.
+    pub fn col_prefix_sums<N, F>(&self, f: F) -> Vec<N>
+        where N : Copy + Add<Output = N> + Default, F : Fn(Line<T, L>) -> N
+    {
+        running_sums(self.columns().map(f))
+    }
+}
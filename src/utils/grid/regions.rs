@@ -0,0 +1,116 @@
+use std::collections::VecDeque;
+
+use crate::utils::geom::{Coord, Rect};
+
+use super::{Grid, Layout, RowMajor};
+
+/// Which cells count as touching when filling.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Connectivity {
+    /// Only orthogonal neighbors
+    #[default]
+    Four,
+    /// Orthogonal and diagonal neighbors
+    Eight,
+}
+
+/// A connected set of cells found by [`Grid::flood_fill`] or [`Grid::components`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Region {
+    /// Every cell in the region, in the order the fill reached them
+    pub cells: Vec<Coord>,
+    /// The smallest rectangle containing every cell
+    pub bounds: Rect,
+    /// Number of cell edges between the region and anything outside it, including the edge of the grid
+    pub perimeter: usize,
+}
+
+impl Region {
+    pub fn area(&self) -> usize {
+        self.cells.len()
+    }
+}
+
+/// Every region of a grid, along with which region each cell belongs to.
+#[derive(Clone)]
+pub struct Components<L = RowMajor> {
+    /// Index into `regions` for each cell, `None` for cells the predicate rejected
+    pub labels: Grid<Option<usize>, L>,
+    pub regions: Vec<Region>,
+}
+
+impl<T, L : Layout> Grid<T, L> {
+    /// Fills outward from `start` through cells that are `passable`. The region is empty if `start` itself isn't.
+    pub fn flood_fill<F>(&self, start: impl Into<Coord>, passable: F, connectivity: Connectivity) -> Region
+        where F : Fn(&T) -> bool
+    {
+        let mut labels = Grid::<_, L>::new_from(self.width, self.height, |_| None);
+        self.fill_region(start.into(), &passable, connectivity, &mut labels, 0)
+    }
+
+    /// Splits the cells matching `predicate` into connected regions, labeled in reading order.
+    pub fn components<F>(&self, predicate: F, connectivity: Connectivity) -> Components<L>
+        where F : Fn(&T) -> bool
+    {
+        let mut labels = Grid::new_from(self.width, self.height, |_| None);
+        let mut regions = Vec::new();
+
+        for y in 0..self.height {
+            for x in 0..self.width {
+                if labels[(x, y)].is_none() && predicate(&self[(x, y)]) {
+                    let region = self.fill_region(Coord::new(x, y), &predicate, connectivity, &mut labels, regions.len());
+                    regions.push(region);
+                }
+            }
+        }
+
+        Components { labels, regions }
+    }
+
+    fn fill_region<F>(
+        &self,
+        start: Coord,
+        passable: &F,
+        connectivity: Connectivity,
+        labels: &mut Grid<Option<usize>, L>,
+        label: usize,
+    ) -> Region
+        where F : Fn(&T) -> bool
+    {
+        let mut cells = Vec::new();
+
+        if self.get(start).is_some_and(passable) {
+            labels[start] = Some(label);
+            let mut queue = VecDeque::from([start]);
+
+            while let Some(pos) = queue.pop_front() {
+                cells.push(pos);
+                let neighbors = match connectivity {
+                    Connectivity::Four => self.neighbors4(pos),
+                    Connectivity::Eight => self.neighbors8(pos),
+                };
+                for (neighbor, item) in neighbors {
+                    if labels[neighbor].is_none() && passable(item) {
+                        labels[neighbor] = Some(label);
+                        queue.push_back(neighbor);
+                    }
+                }
+            }
+        }
+
+        let bounds = match (cells.iter().map(|c| c.x).min(), cells.iter().map(|c| c.y).min()) {
+            (Some(min_x), Some(min_y)) => {
+                let max_x = cells.iter().map(|c| c.x).max().unwrap();
+                let max_y = cells.iter().map(|c| c.y).max().unwrap();
+                Rect::new(min_x, min_y, max_x - min_x + 1, max_y - min_y + 1)
+            }
+            _ => Rect::default(),
+        };
+
+        let perimeter = cells.iter()
+            .map(|&pos| 4 - self.neighbors4(pos).filter(|&(n, _)| labels[n] == Some(label)).count())
+            .sum();
+
+        Region { cells, bounds, perimeter }
+    }
+}
@@ -0,0 +1,134 @@
+use std::{collections::HashSet, fmt::Display};
+
+use colored::{Color, Colorize};
+use itertools::Itertools;
+
+use super::{Grid, GridLike, Layout, SparseGrid};
+
+type CellFn<'a, T> = Box<dyn Fn(&T) -> String + 'a>;
+
+/// Configurable text rendering of a [`Grid`] or [`SparseGrid`], made with `render` or `render_with`.
+///
+/// ```ignore
+/// println!("{}", grid.render().compact().overlay(path, Color::Green).rulers());
+/// ```
+pub struct GridRenderer<'a, G : GridLike> {
+    grid: &'a G,
+    cell: CellFn<'a, G::Item>,
+    compact: bool,
+    overlays: Vec<(HashSet<G::Pos>, Color)>,
+    rulers: bool,
+}
+
+impl<'a, G : GridLike> GridRenderer<'a, G> {
+    fn new<D : Display>(grid: &'a G, cell: impl Fn(&G::Item) -> D + 'a) -> Self {
+        GridRenderer {
+            grid,
+            cell: Box::new(move |item| cell(item).to_string()),
+            compact: false,
+            overlays: Vec::new(),
+            rulers: false,
+        }
+    }
+}
+
+impl<T : Display, L : Layout> Grid<T, L> {
+    /// Renders each cell with its `Display` implementation.
+    pub fn render(&self) -> GridRenderer<'_, Grid<T, L>> {
+        self.render_with(|item| item.to_string())
+    }
+}
+
+impl<T, L : Layout> Grid<T, L> {
+    /// Renders each cell with `cell`.
+    pub fn render_with<'a, D : Display>(&'a self, cell: impl Fn(&T) -> D + 'a) -> GridRenderer<'a, Grid<T, L>> {
+        GridRenderer::new(self, cell)
+    }
+}
+
+impl<T : Display> SparseGrid<T> {
+    /// Renders each cell inside the bounds with its `Display` implementation.
+    pub fn render(&self) -> GridRenderer<'_, SparseGrid<T>> {
+        self.render_with(|item| item.to_string())
+    }
+}
+
+impl<T> SparseGrid<T> {
+    /// Renders each cell inside the bounds with `cell`.
+    pub fn render_with<'a, D : Display>(&'a self, cell: impl Fn(&T) -> D + 'a) -> GridRenderer<'a, SparseGrid<T>> {
+        GridRenderer::new(self, cell)
+    }
+}
+
+impl<G : GridLike> GridRenderer<'_, G> {
+    /// Leaves out the space between cells, so a `Grid<char>` renders the same as its input.
+    pub fn compact(self) -> Self {
+        GridRenderer { compact: true, ..self }
+    }
+
+    /// Colors the cells at `coords`. Later overlays are drawn over earlier ones.
+    pub fn overlay<C : Into<G::Pos>>(mut self, coords: impl IntoIterator<Item = C>, color: Color) -> Self {
+        self.overlays.push((coords.into_iter().map(Into::into).collect(), color));
+        self
+    }
+
+    /// Numbers the rows down the left and the columns across the top.
+    pub fn rulers(self) -> Self {
+        GridRenderer { rulers: true, ..self }
+    }
+}
+
+impl<G : GridLike> Display for GridRenderer<'_, G> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let grid = self.grid;
+        let (origin, width, height) = grid.extent();
+        let (origin_x, origin_y) = G::signed(origin);
+        let positions = grid.positions().collect_vec();
+        let cells = positions.iter()
+            .map(|&pos| grid.cell(pos).map_or(String::new(), |item| (self.cell)(item)))
+            .collect_vec();
+        let cell_width = cells.iter().map(|s| s.chars().count()).max().unwrap_or(0);
+        let separator = if self.compact { "" } else { " " };
+        let label_width = |start: isize, n: usize| (start..start + n as isize).map(|i| i.to_string().len()).max().unwrap_or(1);
+        let row_label_width = label_width(origin_y, height);
+
+        if self.rulers && width > 0 {
+            // Column numbers are written top to bottom when they don't fit across a cell
+            let col_digits = label_width(origin_x, width);
+            let labels = (0..width).map(|x| format!("{:>width$}", origin_x + x as isize, width = col_digits)).collect_vec();
+            let lines = if col_digits <= cell_width { 1 } else { col_digits };
+
+            for line in 0..lines {
+                write!(f, "{:width$} ", "", width = row_label_width)?;
+                let header = labels.iter()
+                    .map(|label| {
+                        let text = if lines == 1 { label.as_str() } else { &label[line..line + 1] };
+                        format!("{:>width$}", text, width = cell_width)
+                    })
+                    .join(separator);
+                writeln!(f, "{}", header)?;
+            }
+        }
+
+        for (y, (row, row_positions)) in cells.chunks(width.max(1)).zip(positions.chunks(width.max(1))).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            if self.rulers {
+                write!(f, "{:>width$} ", origin_y + y as isize, width = row_label_width)?;
+            }
+            for (x, (cell, pos)) in row.iter().zip(row_positions).enumerate() {
+                if x > 0 {
+                    write!(f, "{}", separator)?;
+                }
+                let cell = format!("{:>width$}", cell, width = cell_width);
+                match self.overlays.iter().rev().find(|(coords, _)| coords.contains(pos)) {
+                    Some((_, color)) => write!(f, "{}", cell.color(*color).bold())?,
+                    None => write!(f, "{}", cell)?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,79 @@
+use std::ops::Range;
+
+use crate::utils::geom::Coord;
+
+use super::{Grid, Layout, Line, RowMajor};
+
+/// A horizontal run of cells in one row, like a number in a line of puzzle input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Span {
+    pub y: usize,
+    pub xs: Range<usize>,
+}
+
+impl Span {
+    pub fn len(&self) -> usize {
+        self.xs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.xs.is_empty()
+    }
+
+    pub fn cells(&self) -> impl Iterator<Item = Coord> + '_ {
+        self.xs.clone().map(|x| Coord::new(x, self.y))
+    }
+}
+
+/// Iterator returned by [`Grid::runs`].
+pub struct Runs<'a, T, F, L = RowMajor> {
+    grid: &'a Grid<T, L>,
+    predicate: F,
+    x: usize,
+    y: usize,
+}
+
+impl<'a, T, F : Fn(&T) -> bool, L : Layout> Iterator for Runs<'a, T, F, L> {
+    type Item = (Span, Line<'a, T, L>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let matches = |x, y| (self.predicate)(&self.grid[(x, y)]);
+        while self.y < self.grid.height {
+            while self.x < self.grid.width && !matches(self.x, self.y) {
+                self.x += 1;
+            }
+
+            if self.x < self.grid.width {
+                let start = self.x;
+                while self.x < self.grid.width && matches(self.x, self.y) {
+                    self.x += 1;
+                }
+                return Some((Span { y: self.y, xs: start..self.x }, Line::new(self.grid, self.y, true, start..self.x)));
+            }
+
+            self.x = 0;
+            self.y += 1;
+        }
+        None
+    }
+}
+
+impl<T, L : Layout> Grid<T, L> {
+    /// Every maximal horizontal run of cells matching `predicate`, in reading order, along with its cells.
+    pub fn runs<F : Fn(&T) -> bool>(&self, predicate: F) -> Runs<'_, T, F, L> {
+        Runs { grid: self, predicate, x: 0, y: 0 }
+    }
+
+    /// The cells bordering `span`, including diagonally, in reading order. Cells off the grid are skipped.
+    pub fn span_neighbors(&self, span: &Span) -> impl Iterator<Item = (Coord, &T)> + '_ {
+        let (y, start, end) = (span.y as isize, span.xs.start as isize, span.xs.end as isize);
+        let above = (start - 1..=end).map(move |x| (x, y - 1));
+        let sides = [(start - 1, y), (end, y)];
+        let below = (start - 1..=end).map(move |x| (x, y + 1));
+
+        above.chain(sides).chain(below)
+            .filter_map(Coord::from_signed)
+            .filter(|&pos| self.in_bounds(pos))
+            .map(|pos| (pos, &self[pos]))
+    }
+}
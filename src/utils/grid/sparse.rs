@@ -0,0 +1,160 @@
+use std::{collections::HashMap, fmt::Display, ops::{Index, IndexMut}};
+
+use super::{Grid, GridLike, Layout};
+
+/// A grid over the whole signed plane that only stores cells which have been set. Every other cell
+/// reads as the default value. The bounds grow and shrink to fit the stored cells.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T> {
+    cells: HashMap<(isize, isize), T>,
+    default: T,
+    /// Inclusive top left and bottom right of the stored cells
+    bounds: Option<((isize, isize), (isize, isize))>,
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new(default: T) -> SparseGrid<T> {
+        SparseGrid { cells: HashMap::new(), default, bounds: None }
+    }
+
+    /// The cell at `pos`, or the default if it was never set.
+    pub fn get(&self, pos: (isize, isize)) -> &T {
+        self.cells.get(&pos).unwrap_or(&self.default)
+    }
+
+    /// Whether `pos` holds a stored value, even one equal to the default.
+    pub fn contains(&self, pos: (isize, isize)) -> bool {
+        self.cells.contains_key(&pos)
+    }
+
+    pub fn insert(&mut self, pos: (isize, isize), value: T) -> Option<T> {
+        let (x, y) = pos;
+        self.bounds = Some(match self.bounds {
+            None => (pos, pos),
+            Some(((min_x, min_y), (max_x, max_y))) => ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))),
+        });
+        self.cells.insert(pos, value)
+    }
+
+    pub fn remove(&mut self, pos: (isize, isize)) -> Option<T> {
+        let removed = self.cells.remove(&pos)?;
+        if let Some(((min_x, min_y), (max_x, max_y))) = self.bounds {
+            let (x, y) = pos;
+            if x == min_x || x == max_x || y == min_y || y == max_y {
+                self.recompute_bounds();
+            }
+        }
+        Some(removed)
+    }
+
+    fn recompute_bounds(&mut self) {
+        self.bounds = self.cells.keys().fold(None, |bounds, &(x, y)| Some(match bounds {
+            None => ((x, y), (x, y)),
+            Some(((min_x, min_y), (max_x, max_y))) => ((min_x.min(x), min_y.min(y)), (max_x.max(x), max_y.max(y))),
+        }));
+    }
+
+    /// Inclusive top left and bottom right corners of the stored cells, `None` if there are none.
+    pub fn bounds(&self) -> Option<((isize, isize), (isize, isize))> {
+        self.bounds
+    }
+
+    pub fn width(&self) -> usize {
+        self.bounds.map_or(0, |((min_x, _), (max_x, _))| max_x.abs_diff(min_x) + 1)
+    }
+
+    pub fn height(&self) -> usize {
+        self.bounds.map_or(0, |((_, min_y), (_, max_y))| max_y.abs_diff(min_y) + 1)
+    }
+
+    pub fn default_value(&self) -> &T {
+        &self.default
+    }
+
+    /// Number of stored cells.
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// The stored cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = ((isize, isize), &T)> {
+        self.cells.iter().map(|(&pos, item)| (pos, item))
+    }
+}
+
+impl<T : Clone> SparseGrid<T> {
+    /// The cell at `pos`, storing a copy of the default there first if it was never set.
+    pub fn get_mut(&mut self, pos: (isize, isize)) -> &mut T {
+        if !self.cells.contains_key(&pos) {
+            self.insert(pos, self.default.clone());
+        }
+        self.cells.get_mut(&pos).unwrap()
+    }
+
+    /// Stores every cell of `grid` that isn't `default`, keeping the same coordinates.
+    pub fn from_grid<L : Layout>(grid: &Grid<T, L>, default: T) -> SparseGrid<T>
+        where T : PartialEq
+    {
+        let mut sparse = SparseGrid::new(default);
+        for ((x, y), item) in grid.with_indices() {
+            if *item != sparse.default {
+                sparse.insert((x as isize, y as isize), item.clone());
+            }
+        }
+        sparse
+    }
+
+    /// Copies the cells inside [`SparseGrid::bounds`] into a dense grid.
+    pub fn to_grid(&self) -> Grid<T> {
+        let ((min_x, min_y), _) = self.bounds.unwrap_or_default();
+        Grid::new_from(self.width(), self.height(), |(x, y)| {
+            self.get((min_x + x as isize, min_y + y as isize)).clone()
+        })
+    }
+}
+
+impl<T> GridLike for SparseGrid<T> {
+    type Item = T;
+    type Pos = (isize, isize);
+
+    fn cell(&self, pos: (isize, isize)) -> Option<&T> {
+        Some(self.get(pos))
+    }
+
+    fn offset(&self, (x, y): (isize, isize), (dx, dy): (isize, isize)) -> Option<(isize, isize)> {
+        Some((x + dx, y + dy))
+    }
+
+    fn extent(&self) -> ((isize, isize), usize, usize) {
+        let (min, _) = self.bounds.unwrap_or_default();
+        (min, self.width(), self.height())
+    }
+
+    fn signed(pos: (isize, isize)) -> (isize, isize) {
+        pos
+    }
+}
+
+impl<T> Index<(isize, isize)> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, index: (isize, isize)) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<T : Clone> IndexMut<(isize, isize)> for SparseGrid<T> {
+    fn index_mut(&mut self, index: (isize, isize)) -> &mut Self::Output {
+        self.get_mut(index)
+    }
+}
+
+impl<T : Display> Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render())
+    }
+}
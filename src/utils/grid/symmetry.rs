@@ -0,0 +1,150 @@
+use crate::utils::geom::Coord;
+
+use super::{BitGrid, Grid, Layout, Transform};
+
+/// Which way a mirror line runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Axis {
+    /// Between two rows
+    Horizontal,
+    /// Between two columns
+    Vertical,
+}
+
+/// A line the grid is symmetric across, allowing for a few mismatched cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReflectionAxis {
+    pub axis: Axis,
+    /// Number of rows above a horizontal line, or columns left of a vertical one
+    pub position: usize,
+    /// Each pair of mirrored cells that differ, nearer the line first
+    pub mismatches: Vec<(Coord, Coord)>,
+}
+
+impl ReflectionAxis {
+    pub fn defects(&self) -> usize {
+        self.mismatches.len()
+    }
+}
+
+/// A rotation the grid looks the same under, allowing for a few mismatched cells.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RotationalSymmetry {
+    /// [`Transform::Rotate180`] or [`Transform::RotateCw`]
+    pub transform: Transform,
+    /// Each cell that differs from the cell the rotation moves onto it, paired with that cell.
+    /// Pairs that would only repeat under a half turn are listed once.
+    pub mismatches: Vec<(Coord, Coord)>,
+}
+
+impl RotationalSymmetry {
+    pub fn defects(&self) -> usize {
+        self.mismatches.len()
+    }
+}
+
+/// Mirror lines across `axis` where mirrored lines of cells differ in at most `tolerance` places.
+/// There are `len` lines of cells, and `differences(a, b)` lists the offsets along them where lines
+/// `a` and `b` differ.
+fn mirror_lines(axis: Axis, len: usize, tolerance: usize, differences: impl Fn(usize, usize) -> Vec<usize>) -> Vec<ReflectionAxis> {
+    let coord = |along: usize, across: usize| match axis {
+        Axis::Horizontal => Coord::new(along, across),
+        Axis::Vertical => Coord::new(across, along),
+    };
+
+    (1..len)
+        .filter_map(|position| {
+            let mut mismatches = Vec::new();
+            for (before, after) in (0..position).rev().zip(position..len) {
+                mismatches.extend(differences(before, after).into_iter().map(|i| (coord(i, before), coord(i, after))));
+                if mismatches.len() > tolerance {
+                    return None;
+                }
+            }
+            Some(ReflectionAxis { axis, position, mismatches })
+        })
+        .collect()
+}
+
+/// Horizontal lines first, then vertical, each in order of position.
+fn reflection_axes(
+    (width, height): (usize, usize),
+    tolerance: usize,
+    row_differences: impl Fn(usize, usize) -> Vec<usize>,
+    col_differences: impl Fn(usize, usize) -> Vec<usize>,
+) -> Vec<ReflectionAxis> {
+    let mut axes = mirror_lines(Axis::Horizontal, height, tolerance, row_differences);
+    axes.extend(mirror_lines(Axis::Vertical, width, tolerance, col_differences));
+    axes
+}
+
+impl<T : PartialEq, L : Layout> Grid<T, L> {
+    /// Every horizontal and vertical mirror line with at most `tolerance` mismatched pairs of cells.
+    /// A `tolerance` of 0 finds perfect reflections.
+    pub fn reflection_axes(&self, tolerance: usize) -> Vec<ReflectionAxis> {
+        reflection_axes(
+            (self.width, self.height),
+            tolerance,
+            |y1, y2| (0..self.width).filter(|&x| self[(x, y1)] != self[(x, y2)]).collect(),
+            |x1, x2| (0..self.height).filter(|&y| self[(x1, y)] != self[(x2, y)]).collect(),
+        )
+    }
+}
+
+impl<T : PartialEq, L : Layout> Grid<T, L> {
+    /// The half turn and, for a square grid, the quarter turn, if they leave at most `tolerance`
+    /// mismatched pairs of cells.
+    pub fn rotational_symmetries(&self, tolerance: usize) -> Vec<RotationalSymmetry> {
+        let mut turns = vec![Transform::Rotate180];
+        if self.width == self.height {
+            turns.push(Transform::RotateCw);
+        }
+
+        turns.into_iter()
+            .filter_map(|transform| {
+                let view = self.view(transform);
+                let mut mismatches = Vec::new();
+                for (pos, item) in self.with_indices() {
+                    let pos = Coord::from(pos);
+                    let source = view.source_coord(pos);
+                    let repeated = transform == Transform::Rotate180 && (source.y, source.x) <= (pos.y, pos.x);
+                    if !repeated && *item != self[source] {
+                        mismatches.push((pos, source));
+                        if mismatches.len() > tolerance {
+                            return None;
+                        }
+                    }
+                }
+                Some(RotationalSymmetry { transform, mismatches })
+            })
+            .collect()
+    }
+}
+
+/// Positions of the set bits across a row's words.
+fn set_bits(words: impl Iterator<Item = u64>) -> Vec<usize> {
+    let mut bits = Vec::new();
+    for (i, mut word) in words.enumerate() {
+        while word != 0 {
+            bits.push(i * u64::BITS as usize + word.trailing_zeros() as usize);
+            word &= word - 1;
+        }
+    }
+    bits
+}
+
+impl BitGrid {
+    /// Like [`Grid::reflection_axes`], but rows and columns are compared a word at a time.
+    pub fn reflection_axes(&self, tolerance: usize) -> Vec<ReflectionAxis> {
+        let transposed = self.transpose();
+        let differences = |grid: &BitGrid, a: usize, b: usize| {
+            set_bits(grid.row(a).iter().zip(grid.row(b)).map(|(x, y)| x ^ y))
+        };
+        reflection_axes(
+            (self.width(), self.height()),
+            tolerance,
+            |y1, y2| differences(self, y1, y2),
+            |x1, x2| differences(&transposed, x1, x2),
+        )
+    }
+}
@@ -0,0 +1,84 @@
+use std::ops::Index;
+
+use crate::utils::geom::Coord;
+
+use super::{Grid, GridLike, Layout, RowMajor};
+
+/// A [`Grid`] repeated forever in every direction, made with [`Grid::tiled`]. Any coordinate is
+/// valid and wraps around onto the base grid, with tile `(0, 0)` being the base grid itself.
+pub struct TiledGrid<'a, T, L = RowMajor> {
+    grid: &'a Grid<T, L>,
+}
+
+impl<T, L : Layout> Clone for TiledGrid<'_, T, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, L : Layout> Copy for TiledGrid<'_, T, L> {}
+
+impl<'a, T, L : Layout> TiledGrid<'a, T, L> {
+    /// Panics if `grid` is empty, since there would be nothing to tile.
+    pub fn new(grid: &'a Grid<T, L>) -> TiledGrid<'a, T, L> {
+        if grid.width == 0 || grid.height == 0 {
+            panic!("Cannot tile an empty {}x{} grid", grid.width, grid.height);
+        }
+        TiledGrid { grid }
+    }
+
+    pub fn base(&self) -> &'a Grid<T, L> {
+        self.grid
+    }
+
+    /// The cell of the base grid that `pos` lands on.
+    pub fn wrap(&self, (x, y): (i64, i64)) -> Coord {
+        Coord::new(x.rem_euclid(self.grid.width as i64) as usize, y.rem_euclid(self.grid.height as i64) as usize)
+    }
+
+    /// Which copy of the base grid `pos` falls in, counting tiles left/up as negative.
+    pub fn tile(&self, (x, y): (i64, i64)) -> (i64, i64) {
+        (x.div_euclid(self.grid.width as i64), y.div_euclid(self.grid.height as i64))
+    }
+
+    pub fn get(&self, pos: (i64, i64)) -> &'a T {
+        &self.grid[self.wrap(pos)]
+    }
+}
+
+impl<T, L : Layout> Index<(i64, i64)> for TiledGrid<'_, T, L> {
+    type Output = T;
+
+    fn index(&self, index: (i64, i64)) -> &Self::Output {
+        self.get(index)
+    }
+}
+
+impl<T, L : Layout> GridLike for TiledGrid<'_, T, L> {
+    type Item = T;
+    type Pos = (i64, i64);
+
+    fn cell(&self, pos: (i64, i64)) -> Option<&T> {
+        Some(self.get(pos))
+    }
+
+    fn offset(&self, (x, y): (i64, i64), (dx, dy): (isize, isize)) -> Option<(i64, i64)> {
+        Some((x + dx as i64, y + dy as i64))
+    }
+
+    /// Just the base tile, since the whole plane can't be shown.
+    fn extent(&self) -> ((i64, i64), usize, usize) {
+        ((0, 0), self.grid.width, self.grid.height)
+    }
+
+    fn signed((x, y): (i64, i64)) -> (isize, isize) {
+        (x as isize, y as isize)
+    }
+}
+
+impl<T, L : Layout> Grid<T, L> {
+    /// A view of this grid repeated forever in every direction.
+    pub fn tiled(&self) -> TiledGrid<'_, T, L> {
+        TiledGrid::new(self)
+    }
+}
@@ -0,0 +1,320 @@
+use std::ops::Index;
+
+use itertools::Itertools;
+
+use crate::utils::geom::{Coord, Rect};
+
+use super::{Grid, Layout, Line, RowMajor};
+
+/// One of the 8 symmetries of a rectangle.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Transform {
+    Identity,
+    RotateCw,
+    Rotate180,
+    RotateCcw,
+    /// Mirror left to right
+    FlipHorizontal,
+    /// Mirror top to bottom
+    FlipVertical,
+    /// Mirror across the main diagonal
+    Transpose,
+    /// Mirror across the anti-diagonal
+    AntiTranspose,
+}
+
+impl Transform {
+    pub const fn all() -> [Transform; 8] {
+        [
+            Transform::Identity,
+            Transform::RotateCw,
+            Transform::Rotate180,
+            Transform::RotateCcw,
+            Transform::FlipHorizontal,
+            Transform::FlipVertical,
+            Transform::Transpose,
+            Transform::AntiTranspose,
+        ]
+    }
+
+    // A view coordinate maps to the source by optionally swapping x and y, then optionally
+    // mirroring each axis of the source.
+    fn parts(self) -> (bool, bool, bool) {
+        match self {
+            Transform::Identity => (false, false, false),
+            Transform::RotateCw => (true, false, true),
+            Transform::Rotate180 => (false, true, true),
+            Transform::RotateCcw => (true, true, false),
+            Transform::FlipHorizontal => (false, true, false),
+            Transform::FlipVertical => (false, false, true),
+            Transform::Transpose => (true, false, false),
+            Transform::AntiTranspose => (true, true, true),
+        }
+    }
+
+    fn from_parts(parts: (bool, bool, bool)) -> Transform {
+        Transform::all().into_iter().find(|t| t.parts() == parts).unwrap()
+    }
+
+    /// Whether the width and height are exchanged.
+    pub fn swaps_axes(self) -> bool {
+        self.parts().0
+    }
+
+    /// The transform equivalent to applying `self` and then `next`.
+    pub fn then(self, next: Transform) -> Transform {
+        let (swap, flip_x, flip_y) = self.parts();
+        let (next_swap, next_flip_x, next_flip_y) = next.parts();
+        // Flips from `next` act on our output axes, which are swapped relative to the source when we swap
+        let (next_flip_x, next_flip_y) = if swap { (next_flip_y, next_flip_x) } else { (next_flip_x, next_flip_y) };
+        Transform::from_parts((swap ^ next_swap, flip_x ^ next_flip_x, flip_y ^ next_flip_y))
+    }
+
+    pub fn inverse(self) -> Transform {
+        match self {
+            Transform::RotateCw => Transform::RotateCcw,
+            Transform::RotateCcw => Transform::RotateCw,
+            other => other,
+        }
+    }
+}
+
+/// A grid seen through a [`Transform`], without copying it.
+pub struct GridView<'a, T, L = RowMajor> {
+    grid: &'a Grid<T, L>,
+    transform: Transform,
+}
+
+impl<T, L : Layout> Clone for GridView<'_, T, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, L : Layout> Copy for GridView<'_, T, L> {}
+
+impl<'a, T, L : Layout> GridView<'a, T, L> {
+    pub fn new(grid: &'a Grid<T, L>, transform: Transform) -> GridView<'a, T, L> {
+        GridView { grid, transform }
+    }
+
+    pub fn width(&self) -> usize {
+        if self.transform.swaps_axes() { self.grid.height } else { self.grid.width }
+    }
+
+    pub fn height(&self) -> usize {
+        if self.transform.swaps_axes() { self.grid.width } else { self.grid.height }
+    }
+
+    pub fn transform(&self) -> Transform {
+        self.transform
+    }
+
+    /// Applies another transform on top of this one.
+    pub fn view(&self, transform: Transform) -> GridView<'a, T, L> {
+        GridView { grid: self.grid, transform: self.transform.then(transform) }
+    }
+
+    /// The position in the underlying grid that `coord` in the view refers to.
+    pub fn source_coord(&self, coord: impl Into<Coord>) -> Coord {
+        let Coord { x, y } = coord.into();
+        let (swap, flip_x, flip_y) = self.transform.parts();
+        let (x, y) = if swap { (y, x) } else { (x, y) };
+        Coord {
+            x: if flip_x { self.grid.width - 1 - x } else { x },
+            y: if flip_y { self.grid.height - 1 - y } else { y },
+        }
+    }
+
+    pub fn get(&self, coord: impl Into<Coord>) -> Option<&'a T> {
+        let coord = coord.into();
+        if coord.x < self.width() && coord.y < self.height() {
+            Some(&self.grid[self.source_coord(coord)])
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = ViewLine<'a, T, L>> + ExactSizeIterator {
+        let view = *self;
+        (0..self.height()).map(move |y| ViewLine { view, line: y, start: 0, end: view.width(), horizontal: true })
+    }
+
+    pub fn columns(&self) -> impl DoubleEndedIterator<Item = ViewLine<'a, T, L>> + ExactSizeIterator {
+        let view = *self;
+        (0..self.width()).map(move |x| ViewLine { view, line: x, start: 0, end: view.height(), horizontal: false })
+    }
+
+    pub fn with_indices(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        let view = *self;
+        (0..self.height())
+            .cartesian_product(0..self.width())
+            .map(move |(y, x)| ((x, y), &view.grid[view.source_coord((x, y))]))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.with_indices().map(|(_, item)| item)
+    }
+}
+
+impl<T : Clone, L : Layout> GridView<'_, T, L> {
+    /// Copies the view into a new grid.
+    pub fn to_grid(&self) -> Grid<T, L> {
+        Grid::new(self.width(), self.height(), self.iter().cloned().collect_vec())
+    }
+}
+
+impl<T, L : Layout> Index<(usize, usize)> for GridView<'_, T, L> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.grid[self.source_coord(index)]
+    }
+}
+
+impl<T, L : Layout> Index<Coord> for GridView<'_, T, L> {
+    type Output = T;
+
+    fn index(&self, index: Coord) -> &Self::Output {
+        &self.grid[self.source_coord(index)]
+    }
+}
+
+/// A single row or column of a [`GridView`].
+pub struct ViewLine<'a, T, L = RowMajor> {
+    view: GridView<'a, T, L>,
+    line: usize,
+    start: usize,
+    end: usize,
+    horizontal: bool,
+}
+
+impl<'a, T, L : Layout> ViewLine<'a, T, L> {
+    fn at(&self, i: usize) -> &'a T {
+        let coord = if self.horizontal { (i, self.line) } else { (self.line, i) };
+        &self.view.grid[self.view.source_coord(coord)]
+    }
+}
+
+impl<'a, T, L : Layout> Iterator for ViewLine<'a, T, L> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.start += 1;
+            Some(self.at(self.start - 1))
+        }
+        else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.end - self.start, Some(self.end - self.start))
+    }
+}
+
+impl<T, L : Layout> DoubleEndedIterator for ViewLine<'_, T, L> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.end -= 1;
+            Some(self.at(self.end))
+        }
+        else {
+            None
+        }
+    }
+}
+
+impl<T, L : Layout> ExactSizeIterator for ViewLine<'_, T, L> {}
+
+/// A rectangular block of a [`Grid`], without copying it.
+pub struct SubGrid<'a, T, L = RowMajor> {
+    grid: &'a Grid<T, L>,
+    rect: Rect,
+}
+
+impl<T, L : Layout> Clone for SubGrid<'_, T, L> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, L : Layout> Copy for SubGrid<'_, T, L> {}
+
+impl<'a, T, L : Layout> SubGrid<'a, T, L> {
+    pub fn new(grid: &'a Grid<T, L>, rect: Rect) -> SubGrid<'a, T, L> {
+        if rect.x + rect.width > grid.width || rect.y + rect.height > grid.height {
+            panic!("Subgrid {:?} does not fit in a {}x{} grid", rect, grid.width, grid.height);
+        }
+        SubGrid { grid, rect }
+    }
+
+    pub fn width(&self) -> usize {
+        self.rect.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.rect.height
+    }
+
+    /// Where the subgrid sits in the underlying grid.
+    pub fn rect(&self) -> Rect {
+        self.rect
+    }
+
+    /// The position in the underlying grid that `coord` in the subgrid refers to.
+    pub fn source_coord(&self, coord: impl Into<Coord>) -> Coord {
+        let Coord { x, y } = coord.into();
+        Coord::new(self.rect.x + x, self.rect.y + y)
+    }
+
+    pub fn get(&self, coord: impl Into<Coord>) -> Option<&'a T> {
+        let coord = coord.into();
+        if coord.x < self.width() && coord.y < self.height() {
+            Some(&self.grid[self.source_coord(coord)])
+        }
+        else {
+            None
+        }
+    }
+
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = Line<'a, T, L>> + ExactSizeIterator {
+        let (grid, Rect { x, y, width, height }) = (self.grid, self.rect);
+        (y..y + height).map(move |y| Line::new(grid, y, true, x..x + width))
+    }
+
+    pub fn with_indices(&self) -> impl Iterator<Item = ((usize, usize), &'a T)> {
+        self.rows()
+            .enumerate()
+            .flat_map(|(y, row)| row.enumerate().map(move |(x, item)| ((x, y), item)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> {
+        self.rows().flatten()
+    }
+}
+
+impl<T : Clone, L : Layout> SubGrid<'_, T, L> {
+    /// Copies the subgrid into a new grid.
+    pub fn to_grid(&self) -> Grid<T, L> {
+        Grid::new(self.width(), self.height(), self.iter().cloned().collect_vec())
+    }
+}
+
+impl<T, L : Layout> Index<(usize, usize)> for SubGrid<'_, T, L> {
+    type Output = T;
+
+    fn index(&self, index: (usize, usize)) -> &Self::Output {
+        &self.grid[self.source_coord(index)]
+    }
+}
+
+impl<T, L : Layout> Index<Coord> for SubGrid<'_, T, L> {
+    type Output = T;
+
+    fn index(&self, index: Coord) -> &Self::Output {
+        &self.grid[self.source_coord(index)]
+    }
+}
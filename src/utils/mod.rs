@@ -1 +1,5 @@
 pub mod grid;
+pub mod geom;
+pub mod search;
+pub mod rng;
+pub mod fuzz;
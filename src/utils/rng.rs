@@ -0,0 +1,56 @@
+use std::ops::Range;
+
+/// Small, seedable SplitMix64 generator. Deterministic across platforms so that seeds can be shared.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Uniform value in `0..n`. Panics if `n` is 0.
+    pub fn below(&mut self, n: u64) -> u64 {
+        assert!(n > 0, "Rng::below called with an empty range");
+        // Lemire's multiply-shift, the bias is negligible for the sizes we use
+        ((self.next_u64() as u128 * n as u128) >> 64) as u64
+    }
+
+    pub fn range(&mut self, range: Range<usize>) -> usize {
+        range.start + self.below((range.end - range.start) as u64) as usize
+    }
+
+    pub fn range_i64(&mut self, range: Range<i64>) -> i64 {
+        range.start + self.below(range.end.abs_diff(range.start)) as i64
+    }
+
+    /// Returns true with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.range(0..items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.range(0..i + 1));
+        }
+    }
+
+    /// Derives an independent seed, e.g. for the `n`th case of a run.
+    pub fn derive_seed(seed: u64, n: u64) -> u64 {
+        Rng::new(seed ^ n.wrapping_mul(0xD1B5_4A32_D192_ED03)).next_u64()
+    }
+}
@@ -0,0 +1,170 @@
+//! Shortest path searches over grids or any other state graph.
+//!
+//! States only need to be `Clone + Eq + Hash`, so a state can be as simple as a [`Coord`] or carry
+//! extra information like the direction of travel.
+
+use std::{collections::{BinaryHeap, HashMap, VecDeque, hash_map::Entry}, cmp::Reverse, hash::Hash, ops::Add};
+
+use super::{geom::Coord, grid::{Grid, GridLike, Layout}};
+
+/// A path found by a search, from the start state to the goal state inclusive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    pub states: Vec<S>,
+}
+
+/// Walks parent links back from `end` to rebuild the path.
+fn reconstruct<S : Clone, C>(nodes: &[(S, Option<usize>)], end: usize, cost: C) -> Path<S, C> {
+    let mut states = Vec::new();
+    let mut current = Some(end);
+    while let Some(i) = current {
+        states.push(nodes[i].0.clone());
+        current = nodes[i].1;
+    }
+    states.reverse();
+    Path { cost, states }
+}
+
+/// Fewest-steps path from `start` to the first state satisfying `is_goal`.
+pub fn bfs<S, I>(start: S, mut neighbors: impl FnMut(&S) -> I, mut is_goal: impl FnMut(&S) -> bool) -> Option<Path<S, usize>>
+    where S : Clone + Eq + Hash, I : IntoIterator<Item = S>
+{
+    let mut nodes = vec![(start.clone(), None)];
+    let mut depths = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(i) = queue.pop_front() {
+        let depth = depths[&nodes[i].0];
+        if is_goal(&nodes[i].0) {
+            return Some(reconstruct(&nodes, i, depth));
+        }
+        for next in neighbors(&nodes[i].0) {
+            if let Entry::Vacant(entry) = depths.entry(next.clone()) {
+                entry.insert(depth + 1);
+                nodes.push((next, Some(i)));
+                queue.push_back(nodes.len() - 1);
+            }
+        }
+    }
+
+    None
+}
+
+/// Number of steps from `start` to every reachable state.
+pub fn bfs_distances<S, I>(start: S, neighbors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+    where S : Clone + Eq + Hash, I : IntoIterator<Item = S>
+{
+    bfs_distances_within(start, usize::MAX, neighbors)
+}
+
+/// Like [`bfs_distances`], but stops exploring `max_distance` steps out, so the state space can be
+/// infinite, like a [`TiledGrid`](super::grid::TiledGrid).
+pub fn bfs_distances_within<S, I>(start: S, max_distance: usize, mut neighbors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+    where S : Clone + Eq + Hash, I : IntoIterator<Item = S>
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        let distance = distances[&state];
+        if distance == max_distance {
+            continue;
+        }
+        for next in neighbors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next.clone()) {
+                entry.insert(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Number of steps from `start` to every cell of `grid`, `None` where unreachable.
+pub fn grid_distances<T, L : Layout, I>(grid: &Grid<T, L>, start: impl Into<Coord>, mut neighbors: impl FnMut(Coord) -> I) -> Grid<Option<usize>>
+    where I : IntoIterator<Item = Coord>
+{
+    let start = start.into();
+    let mut distances = Grid::new_from(grid.width, grid.height, |_| None);
+    distances[start] = Some(0);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(pos) = queue.pop_front() {
+        let distance = distances[pos].unwrap();
+        for next in neighbors(pos) {
+            if let Some(slot @ None) = distances.get_mut(next) {
+                *slot = Some(distance + 1);
+                queue.push_back(next);
+            }
+        }
+    }
+
+    distances
+}
+
+/// Fewest-steps orthogonal path through the `passable` cells of a [`Grid`] or [`SparseGrid`](super::grid::SparseGrid).
+pub fn grid_bfs<G : GridLike>(
+    grid: &G,
+    start: G::Pos,
+    passable: impl Fn(&G::Item) -> bool,
+    is_goal: impl FnMut(&G::Pos) -> bool,
+) -> Option<Path<G::Pos, usize>> {
+    let passable = &passable;
+    bfs(start, |&pos| grid.neighbors4(pos).filter(|(_, item)| passable(item)).map(|(n, _)| n), is_goal)
+}
+
+/// Cheapest path from `start` to the first state satisfying `is_goal`, where `neighbors` yields each
+/// next state along with the cost of moving to it. Costs must not be negative.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    neighbors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+    where S : Clone + Eq + Hash, C : Copy + Ord + Add<Output = C> + Default, I : IntoIterator<Item = (S, C)>
+{
+    astar(start, neighbors, |_| C::default(), is_goal)
+}
+
+/// Like [`dijkstra`], but explores states in order of cost so far plus `heuristic`. The heuristic must
+/// never overestimate the remaining cost to a goal, or the path found may not be the cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    mut neighbors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+    where S : Clone + Eq + Hash, C : Copy + Ord + Add<Output = C> + Default, I : IntoIterator<Item = (S, C)>
+{
+    // States live in `nodes` so the heap and parent links can refer to them by index
+    let mut nodes = vec![(start.clone(), None)];
+    let mut best = HashMap::from([(start.clone(), (C::default(), 0))]);
+    let mut heap = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+    while let Some(Reverse((_, cost, i))) = heap.pop() {
+        let state = nodes[i].0.clone();
+        if best[&state] != (cost, i) {
+            // A cheaper way here was found after this entry was queued
+            continue;
+        }
+        if is_goal(&state) {
+            return Some(reconstruct(&nodes, i, cost));
+        }
+        for (next, step_cost) in neighbors(&state) {
+            let next_cost = cost + step_cost;
+            let improved = match best.get(&next) {
+                Some(&(known, _)) => next_cost < known,
+                None => true,
+            };
+            if improved {
+                nodes.push((next.clone(), Some(i)));
+                let j = nodes.len() - 1;
+                best.insert(next.clone(), (next_cost, j));
+                heap.push(Reverse((next_cost + heuristic(&next), next_cost, j)));
+            }
+        }
+    }
+
+    None
+}